@@ -0,0 +1,59 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use advent_of_rust::days::{
+    day01::Day01, day02::Day02, day03::Day03, day04::Day04, day05::Day05, day06::Day06,
+    day07::Day07, day08::Day08, day09::Day09, day10::Day10, day11::Day11, day12::Day12,
+    day13::Day13, day14::Day14, day15::Day15, day16::Day16, day17::Day17, day18::Day18,
+    day19::Day19, day20::Day20, day21::Day21, day22::Day22, day23::Day23, day24::Day24,
+    day25::Day25,
+};
+use advent_of_rust::Day;
+
+/// Benchmarks both parts of a day against its committed input, grouped by day
+/// so `cargo bench -- day-03` runs just the one. Days without an input in
+/// `assets/` are skipped.
+fn bench_day<S: Day>(c: &mut Criterion) {
+    let input = match S::default_input() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Skipped day {}: {}", S::DAY, e);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("day-{:02}", S::DAY));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input))));
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<Day01>(c);
+    bench_day::<Day02>(c);
+    bench_day::<Day03>(c);
+    bench_day::<Day04>(c);
+    bench_day::<Day05>(c);
+    bench_day::<Day06>(c);
+    bench_day::<Day07>(c);
+    bench_day::<Day08>(c);
+    bench_day::<Day09>(c);
+    bench_day::<Day10>(c);
+    bench_day::<Day11>(c);
+    bench_day::<Day12>(c);
+    bench_day::<Day13>(c);
+    bench_day::<Day14>(c);
+    bench_day::<Day15>(c);
+    bench_day::<Day16>(c);
+    bench_day::<Day17>(c);
+    bench_day::<Day18>(c);
+    bench_day::<Day19>(c);
+    bench_day::<Day20>(c);
+    bench_day::<Day21>(c);
+    bench_day::<Day22>(c);
+    bench_day::<Day23>(c);
+    bench_day::<Day24>(c);
+    bench_day::<Day25>(c);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
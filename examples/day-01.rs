@@ -1,16 +1,24 @@
-use std::cmp::Ordering;
-use std::str::FromStr;
+use advent_of_rust::days::day01::{find_2020, find_pair_sorted, parse_list};
+use advent_of_rust::{load_input, AocError};
 
-use advent_of_rust::load_file;
-
-fn main() {
+fn main() -> Result<(), AocError> {
     println!("Hello from day-01!");
 
-    let file_contents = load_file("assets/day-01-a.input").expect("Could not read puzzle file!");
+    let file_contents = load_input("assets/day-01-a.input")?;
 
-    let mut list = parse_list(&file_contents).expect("Failed to load the list!");
+    let mut list = parse_list(&file_contents)?;
     list.sort();
 
+    match find_pair_sorted(&list, 2020) {
+        Some((a, b)) => println!(
+            "Found the pair: {a} + {b} = 2020! {a} * {b} = {product}",
+            a = a,
+            b = b,
+            product = a * b
+        ),
+        None => println!("No pair found. :("),
+    }
+
     match find_2020(&list) {
         Some((a, b, c)) => println!(
             "Found the answer: {a} + {b} + {c} = 2020! {a} * {b} * {c} = {product}",
@@ -21,33 +29,6 @@ fn main() {
         ),
         None => println!("No answer found. :("),
     }
-}
-
-fn parse_list(lines: &str) -> Result<Vec<i32>, std::io::Error> {
-    let numbers = lines
-        .split("\n")
-        .map(|s| i32::from_str(s).unwrap_or(0))
-        .filter(|n| *n > 0 && *n < 2020)
-        .collect::<Vec<i32>>();
-    Ok(numbers)
-}
-
-fn find_2020(list: &[i32]) -> Option<(i32, i32, i32)> {
-    'outer: for a in list.iter() {
-        'middle: for b in list.iter() {
-            if a + b > 2020 {
-                continue 'outer;
-            }
-
-            'inner: for c in list.iter() {
-                match (a + b + c).cmp(&2020) {
-                    Ordering::Greater => continue 'middle,
-                    Ordering::Equal => return Some((*a, *b, *c)),
-                    Ordering::Less => continue 'inner,
-                }
-            }
-        }
-    }
 
-    None
+    Ok(())
 }
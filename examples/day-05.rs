@@ -0,0 +1,37 @@
+use advent_of_rust::days::day05::{find_my_seat, Partition, PassParser};
+use advent_of_rust::{load_file, Logos};
+
+fn main() {
+    println!("Hello from day-05!");
+
+    let file_contents = load_file("assets/day-05-a.input").expect("Could not read puzzle file!");
+    let mut lexer = Partition::lexer(&file_contents);
+
+    let mut parser = PassParser::new(&mut lexer);
+    let seat_ids = parser
+        .by_ref()
+        .map(|pass| pass.seat_id())
+        .collect::<Vec<u32>>();
+
+    let diagnostics = parser.diagnostics();
+    if !diagnostics.is_empty() {
+        let (line, column) = parser.diagnostic_positions()[0];
+        eprintln!(
+            "Skipped {} malformed boarding passes. First at {}:{}: {}",
+            diagnostics.len(),
+            line,
+            column,
+            diagnostics[0].1
+        );
+    }
+
+    match seat_ids.iter().max() {
+        Some(highest) => println!("The highest seat ID is {}.", highest),
+        None => println!("No boarding passes found. :("),
+    }
+
+    match find_my_seat(&seat_ids) {
+        Some(seat) => println!("Found my seat! It's seat {}.", seat),
+        None => println!("Couldn't find my seat. :("),
+    }
+}
@@ -0,0 +1,14 @@
+use advent_of_rust::days::day06::{anyone_count, everyone_count};
+use advent_of_rust::{load_file, paragraphs};
+
+fn main() {
+    println!("Hello from day-06!");
+
+    let file_contents = load_file("assets/day-06-a.input").expect("Could not read puzzle file!");
+
+    let anyone = paragraphs(&file_contents).map(anyone_count).sum::<u32>();
+    println!("Anyone answered yes to {} questions in total.", anyone);
+
+    let everyone = paragraphs(&file_contents).map(everyone_count).sum::<u32>();
+    println!("Everyone answered yes to {} questions in total.", everyone);
+}
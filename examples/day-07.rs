@@ -0,0 +1,19 @@
+use advent_of_rust::days::day07::{containers_of, count_contained, parse_rules, RuleToken};
+use advent_of_rust::{load_file, Logos};
+
+fn main() {
+    println!("Hello from day-07!");
+
+    let file_contents = load_file("assets/day-07-a.input").expect("Could not read puzzle file!");
+    let mut lexer = RuleToken::lexer(&file_contents);
+    let rules = parse_rules(&mut lexer).expect("Could not parse the bag rules!");
+
+    println!(
+        "{} bag colors can eventually contain a shiny gold bag.",
+        containers_of(&rules, "shiny gold")
+    );
+    println!(
+        "A shiny gold bag holds {} other bags.",
+        count_contained(&rules, "shiny gold")
+    );
+}
@@ -0,0 +1,22 @@
+use advent_of_rust::days::day08::{
+    find_terminating_variant, parse_program, Console, OpToken, Outcome,
+};
+use advent_of_rust::{load_file, Logos};
+
+fn main() {
+    println!("Hello from day-08!");
+
+    let file_contents = load_file("assets/day-08-a.input").expect("Could not read puzzle file!");
+    let mut lexer = OpToken::lexer(&file_contents);
+    let program = parse_program(&mut lexer).expect("Could not parse the boot code!");
+
+    match Console::new(&program).run_until_loop_or_end() {
+        Outcome::Looped(acc) => println!("The accumulator is {} before looping.", acc),
+        Outcome::Halted(acc) => println!("The program halted early with {}!", acc),
+    }
+
+    match find_terminating_variant(&program) {
+        Some(acc) => println!("The repaired program halts with {}.", acc),
+        None => println!("Couldn't repair the program. :("),
+    }
+}
@@ -0,0 +1,23 @@
+use advent_of_rust::days::day09::{encryption_weakness, first_invalid, parse_numbers};
+use advent_of_rust::load_file;
+
+fn main() {
+    println!("Hello from day-09!");
+
+    let file_contents = load_file("assets/day-09-a.input").expect("Could not read puzzle file!");
+    let numbers = parse_numbers(&file_contents).expect("Failed to load the numbers!");
+
+    let invalid = match first_invalid(&numbers, 25) {
+        Some(invalid) => invalid,
+        None => {
+            println!("Every number follows the rules. :(");
+            return;
+        }
+    };
+    println!("The first number that breaks the encoding is {}.", invalid);
+
+    match encryption_weakness(&numbers, invalid) {
+        Some(weakness) => println!("The encryption weakness is {}.", weakness),
+        None => println!("No encryption weakness found. :("),
+    }
+}
@@ -0,0 +1,25 @@
+use advent_of_rust::days::day10::{
+    adapter_chain, count_arrangements, jolt_differences, parse_adapters,
+};
+use advent_of_rust::load_file;
+
+fn main() {
+    println!("Hello from day-10!");
+
+    let file_contents = load_file("assets/day-10-a.input").expect("Could not read puzzle file!");
+    let adapters = parse_adapters(&file_contents).expect("Failed to load the adapters!");
+    let chain = adapter_chain(&adapters);
+
+    let (ones, threes) = jolt_differences(&chain);
+    println!(
+        "{ones} 1-jolt differences * {threes} 3-jolt differences = {product}",
+        ones = ones,
+        threes = threes,
+        product = ones * threes
+    );
+
+    println!(
+        "There are {} ways to arrange the adapters.",
+        count_arrangements(&adapters)
+    );
+}
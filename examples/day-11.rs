@@ -0,0 +1,20 @@
+use advent_of_rust::days::day11::{adjacent_occupied, stabilize, visible_occupied, Seat};
+use advent_of_rust::grid::Grid;
+use advent_of_rust::load_file;
+
+fn main() {
+    println!("Hello from day-11!");
+
+    let file_contents = load_file("assets/day-11-a.input").expect("Could not read puzzle file!");
+    let seats =
+        Grid::parse_with(&file_contents, Seat::from_char).expect("Could not parse the seats!");
+
+    println!(
+        "{} seats are occupied looking at neighbors.",
+        stabilize(&seats, adjacent_occupied, 4)
+    );
+    println!(
+        "{} seats are occupied looking along sight lines.",
+        stabilize(&seats, visible_occupied, 5)
+    );
+}
@@ -0,0 +1,19 @@
+use advent_of_rust::days::day12::{navigate, parse_actions, Action, Mode};
+use advent_of_rust::{load_file, Logos};
+
+fn main() {
+    println!("Hello from day-12!");
+
+    let file_contents = load_file("assets/day-12-a.input").expect("Could not read puzzle file!");
+    let actions =
+        parse_actions(&mut Action::lexer(&file_contents)).expect("Could not parse the actions!");
+
+    println!(
+        "The ship ends up {} away from the start.",
+        navigate(&actions, Mode::Heading)
+    );
+    println!(
+        "Following the waypoint, it ends up {} away from the start.",
+        navigate(&actions, Mode::Waypoint)
+    );
+}
@@ -0,0 +1,19 @@
+use advent_of_rust::days::day13::{earliest_bus, earliest_sequence, parse_notes};
+use advent_of_rust::load_file;
+
+fn main() {
+    println!("Hello from day-13!");
+
+    let file_contents = load_file("assets/day-13-a.input").expect("Could not read puzzle file!");
+    let notes = parse_notes(&file_contents).expect("Could not parse the notes!");
+
+    match earliest_bus(&notes) {
+        Some(answer) => println!("Bus ID * minutes waited = {}", answer),
+        None => println!("No buses are running. :("),
+    }
+
+    match earliest_sequence(&notes.buses) {
+        Some(time) => println!("The buses line up at timestamp {}.", time),
+        None => println!("The buses never line up. :("),
+    }
+}
@@ -0,0 +1,13 @@
+use advent_of_rust::days::day14::{parse_program, run_v1, run_v2, Line};
+use advent_of_rust::{load_file, Logos};
+
+fn main() {
+    println!("Hello from day-14!");
+
+    let file_contents = load_file("assets/day-14-a.input").expect("Could not read puzzle file!");
+    let mut lexer = Line::lexer(&file_contents);
+    let program = parse_program(&mut lexer).expect("Could not parse the initialization program!");
+
+    println!("Masking values, memory sums to {}.", run_v1(&program));
+    println!("Masking addresses, memory sums to {}.", run_v2(&program));
+}
@@ -0,0 +1,15 @@
+use advent_of_rust::days::day15::{parse_starting, play};
+use advent_of_rust::load_file;
+
+fn main() {
+    println!("Hello from day-15!");
+
+    let file_contents = load_file("assets/day-15-a.input").expect("Could not read puzzle file!");
+    let starting = parse_starting(&file_contents).expect("Failed to load the starting numbers!");
+
+    println!("The 2020th number spoken is {}.", play(&starting, 2020));
+    println!(
+        "The 30000000th number spoken is {}.",
+        play(&starting, 30_000_000)
+    );
+}
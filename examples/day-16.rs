@@ -0,0 +1,16 @@
+use advent_of_rust::days::day16::parse_notes;
+use advent_of_rust::load_file;
+
+fn main() {
+    println!("Hello from day-16!");
+
+    let file_contents = load_file("assets/day-16-a.input").expect("Could not read puzzle file!");
+    let notes = parse_notes(&file_contents).expect("Could not parse the ticket notes!");
+
+    println!("The ticket scanning error rate is {}.", notes.error_rate());
+
+    match notes.departure_product() {
+        Some(product) => println!("The departure fields multiply to {}.", product),
+        None => println!("Couldn't work out the field order. :("),
+    }
+}
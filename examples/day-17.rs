@@ -0,0 +1,17 @@
+use advent_of_rust::days::day17::boot;
+use advent_of_rust::load_file;
+
+fn main() {
+    println!("Hello from day-17!");
+
+    let file_contents = load_file("assets/day-17-a.input").expect("Could not read puzzle file!");
+
+    println!(
+        "{} cubes are active in 3 dimensions.",
+        boot::<3>(&file_contents, 6)
+    );
+    println!(
+        "{} cubes are active in 4 dimensions.",
+        boot::<4>(&file_contents, 6)
+    );
+}
@@ -0,0 +1,17 @@
+use advent_of_rust::days::day18::{homework_sum, Precedence};
+use advent_of_rust::load_file;
+
+fn main() {
+    println!("Hello from day-18!");
+
+    let file_contents = load_file("assets/day-18-a.input").expect("Could not read puzzle file!");
+
+    match homework_sum(&file_contents, Precedence::Flat) {
+        Ok(sum) => println!("Left to right, the homework sums to {}.", sum),
+        Err(e) => eprintln!("Couldn't finish the homework: {}", e),
+    }
+    match homework_sum(&file_contents, Precedence::AddFirst) {
+        Ok(sum) => println!("Adding first, the homework sums to {}.", sum),
+        Err(e) => eprintln!("Couldn't finish the homework: {}", e),
+    }
+}
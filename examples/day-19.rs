@@ -0,0 +1,18 @@
+use advent_of_rust::days::day19::{count_matches, parse_input, with_loops};
+use advent_of_rust::load_file;
+
+fn main() {
+    println!("Hello from day-19!");
+
+    let file_contents = load_file("assets/day-19-a.input").expect("Could not read puzzle file!");
+    let (rules, messages) = parse_input(&file_contents).expect("Could not parse the messages!");
+
+    println!(
+        "{} messages completely match rule 0.",
+        count_matches(&rules, &messages)
+    );
+    println!(
+        "With the looping rules, {} messages match.",
+        count_matches(&with_loops(&rules), &messages)
+    );
+}
@@ -0,0 +1,24 @@
+use advent_of_rust::days::day20::{
+    assemble, corner_product, count_monsters, parse_tiles, water_roughness,
+};
+use advent_of_rust::load_file;
+
+fn main() {
+    println!("Hello from day-20!");
+
+    let file_contents = load_file("assets/day-20-a.input").expect("Could not read puzzle file!");
+    let tiles = parse_tiles(&file_contents).expect("Could not parse the tiles!");
+
+    println!(
+        "The corner tile IDs multiply to {}.",
+        corner_product(&tiles)
+    );
+
+    match assemble(&tiles) {
+        Some(image) => {
+            println!("Found {} sea monsters.", count_monsters(&image));
+            println!("The water roughness is {}.", water_roughness(&image));
+        }
+        None => println!("Couldn't put the image together. :("),
+    }
+}
@@ -0,0 +1,19 @@
+use advent_of_rust::days::day21::{dangerous_list, parse_foods, safe_appearances};
+use advent_of_rust::load_file;
+
+fn main() {
+    println!("Hello from day-21!");
+
+    let file_contents = load_file("assets/day-21-a.input").expect("Could not read puzzle file!");
+    let foods = parse_foods(&file_contents).expect("Could not parse the food list!");
+
+    println!(
+        "Ingredients without allergens appear {} times.",
+        safe_appearances(&foods)
+    );
+
+    match dangerous_list(&foods) {
+        Some(list) => println!("The canonical dangerous ingredient list is {}", list),
+        None => println!("Couldn't pin down every allergen. :("),
+    }
+}
@@ -0,0 +1,18 @@
+use advent_of_rust::days::day22::{combat, parse_decks, recursive_combat};
+use advent_of_rust::load_file;
+
+fn main() {
+    println!("Hello from day-22!");
+
+    let file_contents = load_file("assets/day-22-a.input").expect("Could not read puzzle file!");
+    let (one, two) = parse_decks(&file_contents).expect("Could not parse the decks!");
+
+    println!(
+        "The winner of Combat scores {}.",
+        combat(one.clone(), two.clone())
+    );
+    println!(
+        "The winner of Recursive Combat scores {}.",
+        recursive_combat(one, two)
+    );
+}
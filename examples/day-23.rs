@@ -0,0 +1,18 @@
+use advent_of_rust::days::day23::{labels_after_one, parse_labels, star_cups};
+use advent_of_rust::load_file;
+
+fn main() {
+    println!("Hello from day-23!");
+
+    let file_contents = load_file("assets/day-23-a.input").expect("Could not read puzzle file!");
+    let labels = parse_labels(&file_contents).expect("Could not parse the cup labels!");
+
+    println!(
+        "After 100 moves, the cups read {}.",
+        labels_after_one(&labels, 100)
+    );
+    println!(
+        "The stars are under cups multiplying to {}.",
+        star_cups(&labels)
+    );
+}
@@ -0,0 +1,16 @@
+use advent_of_rust::days::day24::{after_days, flip_tiles, Step};
+use advent_of_rust::{load_file, Logos};
+
+fn main() {
+    println!("Hello from day-24!");
+
+    let file_contents = load_file("assets/day-24-a.input").expect("Could not read puzzle file!");
+    let black =
+        flip_tiles(&mut Step::lexer(&file_contents)).expect("Could not follow the tile list!");
+
+    println!("{} tiles start with the black side up.", black.len());
+    println!(
+        "After 100 days, {} tiles are black.",
+        after_days(&black, 100).len()
+    );
+}
@@ -0,0 +1,14 @@
+use advent_of_rust::days::day25::{encryption_key, parse_keys};
+use advent_of_rust::load_file;
+
+fn main() {
+    println!("Hello from day-25!");
+
+    let file_contents = load_file("assets/day-25-a.input").expect("Could not read puzzle file!");
+    let (card, door) = parse_keys(&file_contents).expect("Could not parse the public keys!");
+
+    match encryption_key(card, door) {
+        Some(key) => println!("The encryption key is {}.", key),
+        None => println!("Couldn't break the handshake. :("),
+    }
+}
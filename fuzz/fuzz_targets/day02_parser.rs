@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use advent_of_rust::days::day02::summarize;
+
+// Run with `cargo +nightly fuzz run day02_parser`. Every line gets lexed and
+// parsed into a rule, or recorded as an error, but it must never panic.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = summarize(input);
+    }
+});
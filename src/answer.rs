@@ -0,0 +1,94 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A puzzle's answer. Most are numbers, but some days answer with a string of
+/// labels or names instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(untagged))]
+pub enum Answer {
+    Int(i64),
+    Str(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(n: $int) -> Self {
+                    Answer::Int(i64::from(n))
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, u8, u16, u32);
+
+// These can hold numbers too big for an `i64`, which still make perfectly
+// good answers once written out.
+macro_rules! from_wide_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(n: $int) -> Self {
+                    i64::try_from(n)
+                        .map(Answer::Int)
+                        .unwrap_or_else(|_| Answer::Str(n.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+from_wide_int!(u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn int_display() {
+        assert_eq!(Answer::from(514579).to_string(), "514579");
+        assert_eq!(Answer::from(-12i64).to_string(), "-12");
+        assert_eq!(Answer::from(7usize), Answer::Int(7));
+    }
+
+    #[test]
+    fn str_display() {
+        assert_eq!(Answer::from("67384529").to_string(), "67384529");
+        assert_eq!(
+            Answer::from("mxmxvkd,sqjhc,fvjkl".to_string()),
+            Answer::Str("mxmxvkd,sqjhc,fvjkl".to_string())
+        );
+    }
+
+    #[test]
+    fn wide_int_display() {
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(Answer::from(u64::MAX), Answer::Str(u64::MAX.to_string()));
+    }
+}
@@ -0,0 +1,142 @@
+//! Checking solved answers against the ones already known to be right, so a
+//! refactor can't quietly break a day.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use crate::{load_input, Answer, AocError, DayResult};
+
+/// Expected answers by day, then by part. Both are strings in the file, since
+/// TOML and JSON only allow string keys.
+type Expected = BTreeMap<String, BTreeMap<String, Answer>>;
+
+/// A result whose answer isn't the one expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: u8,
+    pub expected: Answer,
+    pub actual: Answer,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Day {} part {} answered {}, but {} was expected",
+            self.day, self.part, self.actual, self.expected
+        )
+    }
+}
+
+/// Loads the expected answers at `path` and compares them against `results`.
+/// Files ending in `.toml` are read as TOML, anything else as JSON, laid out
+/// like `{"1": {"1": 514579, "2": 241861950}}`.
+///
+/// Results without an expected answer are skipped.
+pub fn check_answers<P: AsRef<Path>>(
+    path: P,
+    results: &[DayResult],
+) -> Result<Vec<Mismatch>, AocError> {
+    let path = path.as_ref();
+    let source = load_input(path)?;
+
+    let expected: Expected = if path.extension().is_some_and(|ext| ext == "toml") {
+        toml::from_str(&source).map_err(|e| AocError::Parse(e.to_string()))?
+    } else {
+        serde_json::from_str(&source).map_err(|e| AocError::Parse(e.to_string()))?
+    };
+
+    let mismatches = results
+        .iter()
+        .filter_map(|result| {
+            let expected = expected
+                .get(&result.day.to_string())?
+                .get(&result.part.to_string())?;
+
+            if *expected == result.answer {
+                None
+            } else {
+                Some(Mismatch {
+                    day: result.day,
+                    part: result.part,
+                    expected: expected.clone(),
+                    actual: result.answer.clone(),
+                })
+            }
+        })
+        .collect();
+
+    Ok(mismatches)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use super::*;
+
+    fn fixture(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("advent-of-rust-{}", name));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn results() -> Vec<DayResult> {
+        vec![
+            DayResult::new(1, 1, Answer::Int(514579), Duration::ZERO),
+            DayResult::new(1, 2, Answer::Int(241861950), Duration::ZERO),
+            DayResult::new(23, 1, Answer::from("67384529"), Duration::ZERO),
+        ]
+    }
+
+    #[test]
+    fn matching_json() -> Result<(), AocError> {
+        let path = fixture(
+            "matching.json",
+            r#"{"1": {"1": 514579, "2": 241861950}, "23": {"1": "67384529"}}"#,
+        );
+
+        assert_eq!(check_answers(path, &results())?, vec![]);
+        Ok(())
+    }
+
+    #[test]
+    fn matching_toml() -> Result<(), AocError> {
+        let path = fixture(
+            "matching.toml",
+            "[1]\n1 = 514579\n2 = 241861950\n\n[23]\n1 = \"67384529\"\n",
+        );
+
+        assert_eq!(check_answers(path, &results())?, vec![]);
+        Ok(())
+    }
+
+    #[test]
+    fn wrong_expected_answer() -> Result<(), AocError> {
+        let path = fixture("wrong.json", r#"{"1": {"2": 42}}"#);
+
+        assert_eq!(
+            check_answers(path, &results())?,
+            vec![Mismatch {
+                day: 1,
+                part: 2,
+                expected: Answer::Int(42),
+                actual: Answer::Int(241861950),
+            }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unreadable_expected_answers() {
+        let path = fixture("broken.json", "{\"1\": ");
+
+        assert!(matches!(
+            check_answers(path, &results()),
+            Err(AocError::Parse(_))
+        ));
+    }
+}
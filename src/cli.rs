@@ -0,0 +1,293 @@
+//! The command line interface for running solutions by day.
+
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{Parser, Subcommand, ValueEnum};
+#[cfg(feature = "color")]
+use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use crate::{days, input_path, load_input, parts, timed_value, Answer, AocError, DayResult};
+
+#[derive(Debug, Parser)]
+#[command(name = "advent-of-rust", about = "Solves Advent of Code 2020 puzzles")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, PartialEq, Eq, Subcommand)]
+pub enum Command {
+    /// Solves a day's puzzle, both parts unless `--part` is given.
+    Run {
+        /// Which day to solve, from 1 to 25.
+        #[arg(short, long)]
+        day: u8,
+
+        /// Which part of the day to solve, 1 or 2. Runs both when left out.
+        #[arg(short, long)]
+        part: Option<u8>,
+
+        /// The puzzle input. Defaults to the day's file in `assets/`.
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+
+        /// How to print each answer.
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Fails if any answer differs from the ones in this TOML or JSON file.
+        #[arg(long, value_name = "ANSWERS")]
+        check: Option<PathBuf>,
+    },
+    /// Downloads a day's puzzle input into `assets/`, using the session token
+    /// in `AOC_SESSION`.
+    Download {
+        /// Which day to download, from 1 to 25.
+        #[arg(short, long)]
+        day: u8,
+
+        /// Which year's puzzle to download.
+        #[arg(short, long, default_value_t = 2020)]
+        year: u16,
+
+        /// Replace the input if it's already been downloaded.
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// Times both parts of every day, skipping any whose input hasn't been
+    /// downloaded into `assets/` yet.
+    Bench,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// A labelled line per part.
+    Text,
+    /// A line of JSON per part, with how long it took to solve.
+    Ndjson,
+}
+
+impl Cli {
+    pub fn execute(self) -> Result<(), AocError> {
+        match self.command {
+            Command::Run {
+                day,
+                part,
+                input,
+                format,
+                check,
+            } => {
+                let path = input.unwrap_or_else(|| input_path(day, "a"));
+                let input = load_input(path)?;
+
+                let mut results = Vec::new();
+                for part in parts(part) {
+                    let (answer, elapsed) = timed_value(|| days::solve_part(day, part, &input));
+                    let result = DayResult::new(day, part, answer?, elapsed);
+
+                    match format {
+                        Format::Text => print_answer(part, &result.answer)?,
+                        Format::Ndjson => print_json_line(&result)?,
+                    }
+                    results.push(result);
+                }
+
+                match check {
+                    Some(expected) => check_results(expected, &results),
+                    None => Ok(()),
+                }
+            }
+            Command::Download { day, year, force } => download(day, year, force),
+            Command::Bench => {
+                print!("{}", timing_table(&bench()?));
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn print_json_line(result: &DayResult) -> Result<(), AocError> {
+    println!("{}", result.to_json_line());
+    Ok(())
+}
+
+#[cfg(not(feature = "serde"))]
+fn print_json_line(_: &DayResult) -> Result<(), AocError> {
+    Err(AocError::MissingFeature("serde"))
+}
+
+#[cfg(feature = "serde")]
+fn check_results(expected: PathBuf, results: &[DayResult]) -> Result<(), AocError> {
+    let mismatches = crate::check::check_answers(expected, results)?;
+    for mismatch in mismatches.iter() {
+        eprintln!("{}", mismatch);
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(AocError::WrongAnswers(mismatches.len()))
+    }
+}
+
+#[cfg(not(feature = "serde"))]
+fn check_results(_: PathBuf, _: &[DayResult]) -> Result<(), AocError> {
+    Err(AocError::MissingFeature("serde"))
+}
+
+#[cfg(not(feature = "color"))]
+fn print_answer(part: u8, answer: &Answer) -> Result<(), AocError> {
+    println!("Part {}: {}", part, answer);
+    Ok(())
+}
+
+#[cfg(feature = "color")]
+fn print_answer(part: u8, answer: &Answer) -> Result<(), AocError> {
+    let mut stdout = StandardStream::stdout(color_choice());
+    write_answer(&mut stdout, part, answer)?;
+    Ok(())
+}
+
+/// Colors are only worth printing to a terminal, and never when `NO_COLOR` is
+/// set to anything but an empty string.
+#[cfg(feature = "color")]
+fn color_choice() -> ColorChoice {
+    use std::io::IsTerminal;
+
+    if std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) || !io::stdout().is_terminal() {
+        ColorChoice::Never
+    } else {
+        ColorChoice::Auto
+    }
+}
+
+/// Writes a labelled answer, with the label in bold and the answer in green
+/// when `out` supports color.
+#[cfg(feature = "color")]
+pub fn write_answer(out: &mut impl WriteColor, part: u8, answer: &Answer) -> io::Result<()> {
+    out.set_color(ColorSpec::new().set_bold(true))?;
+    write!(out, "Part {}:", part)?;
+    out.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+    write!(out, " {}", answer)?;
+    out.reset()?;
+    writeln!(out)
+}
+
+fn download(day: u8, year: u16, force: bool) -> Result<(), AocError> {
+    let path = input_path(day, "a");
+    if path.exists() && !force {
+        return Err(AocError::Io(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!(
+                "{} already exists, pass --force to replace it",
+                path.display()
+            ),
+        )));
+    }
+
+    #[cfg(feature = "fetch")]
+    {
+        use crate::fetch::{download_input, session_from_env};
+
+        let input = download_input(year, day, &session_from_env()?)?;
+        std::fs::write(&path, input)?;
+        println!("Saved the input to {}", path.display());
+        Ok(())
+    }
+
+    #[cfg(not(feature = "fetch"))]
+    {
+        let _ = year;
+        Err(AocError::MissingFeature("fetch"))
+    }
+}
+
+fn bench() -> Result<Vec<(u8, u8, Duration)>, AocError> {
+    let mut timings = Vec::new();
+
+    for &day in days::SOLVED {
+        let path = input_path(day, "a");
+        if !path.exists() {
+            eprintln!(
+                "Skipped day {}, there's no input at {}",
+                day,
+                path.display()
+            );
+            continue;
+        }
+        let input = load_input(path)?;
+
+        for part in 1..=2 {
+            let (answer, elapsed) = timed_value(|| days::solve_part(day, part, &input));
+            answer?;
+            timings.push((day, part, elapsed));
+        }
+    }
+
+    Ok(timings)
+}
+
+/// Lines up `(day, part, duration)` timings in a table, with the total at the
+/// bottom.
+pub fn timing_table(timings: &[(u8, u8, Duration)]) -> String {
+    let time = |duration: Duration| format!("{:>12}", format!("{:.3?}", duration));
+
+    let mut table = format!("{:>3} {:>4} {:>12}\n", "Day", "Part", "Time");
+    for (day, part, duration) in timings {
+        table.push_str(&format!("{:>3} {:>4} {}\n", day, part, time(*duration)));
+    }
+
+    let total = timings.iter().map(|(_, _, duration)| *duration).sum();
+    table.push_str(&format!("{:<8} {}\n", "Total", time(total)));
+
+    table
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn timing_table_test() {
+        let timings = [
+            (1, 1, Duration::from_micros(1500)),
+            (1, 2, Duration::from_millis(20)),
+            (12, 1, Duration::from_micros(250)),
+        ];
+
+        assert_eq!(
+            timing_table(&timings),
+            concat!(
+                "Day Part         Time\n",
+                "  1    1      1.500ms\n",
+                "  1    2     20.000ms\n",
+                " 12    1    250.000µs\n",
+                "Total        21.750ms\n",
+            )
+        );
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn write_answer_without_color() -> io::Result<()> {
+        let mut plain = termcolor::Buffer::no_color();
+        write_answer(&mut plain, 2, &Answer::Int(336))?;
+
+        assert_eq!(plain.as_slice(), b"Part 2: 336\n");
+        assert!(!plain.as_slice().contains(&0x1b));
+        Ok(())
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn write_answer_with_color() -> io::Result<()> {
+        let mut colored = termcolor::Buffer::ansi();
+        write_answer(&mut colored, 2, &Answer::Int(336))?;
+
+        assert!(colored.as_slice().contains(&0x1b));
+        Ok(())
+    }
+}
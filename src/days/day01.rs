@@ -0,0 +1,249 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{AocError, Day, Solver};
+
+/// Day 1: Report Repair.
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Output = i64;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        let mut list = parse_list(input)?;
+        list.sort();
+
+        let (a, b) = find_pair_sorted(&list, 2020).ok_or(AocError::NoSolution)?;
+        Ok(i64::from(a) * i64::from(b))
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        let mut list = parse_list(input)?;
+        list.sort();
+
+        let (a, b, c) = find_2020(&list).ok_or(AocError::NoSolution)?;
+        Ok(i64::from(a) * i64::from(b) * i64::from(c))
+    }
+}
+
+impl Day for Day01 {
+    const DAY: u8 = 1;
+}
+
+pub fn parse_list(lines: &str) -> Result<Vec<i32>, std::io::Error> {
+    let numbers = lines
+        .split("\n")
+        .map(|s| i32::from_str(s).unwrap_or(0))
+        .filter(|n| *n > 0 && *n < 2020)
+        .collect::<Vec<i32>>();
+    Ok(numbers)
+}
+
+/// Finds three numbers in `list` that add up to 2020. The list must be sorted
+/// in ascending order.
+pub fn find_2020(list: &[i32]) -> Option<(i32, i32, i32)> {
+    'outer: for a in list.iter() {
+        'middle: for b in list.iter() {
+            if a + b > 2020 {
+                continue 'outer;
+            }
+
+            'inner: for c in list.iter() {
+                match (a + b + c).cmp(&2020) {
+                    Ordering::Greater => continue 'middle,
+                    Ordering::Equal => return Some((*a, *b, *c)),
+                    Ordering::Less => continue 'inner,
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds two numbers in `sorted` that add up to `target`, walking inward from
+/// both ends of the slice.
+///
+/// The slice **must** be sorted in ascending order, otherwise the search will
+/// silently miss valid pairs.
+pub fn find_pair_sorted(sorted: &[i32], target: i32) -> Option<(i32, i32)> {
+    if sorted.is_empty() {
+        return None;
+    }
+
+    let mut low = 0;
+    let mut high = sorted.len() - 1;
+
+    while low < high {
+        match (sorted[low] + sorted[high]).cmp(&target) {
+            Ordering::Greater => high -= 1,
+            Ordering::Equal => return Some((sorted[low], sorted[high])),
+            Ordering::Less => low += 1,
+        }
+    }
+
+    None
+}
+
+/// Every puzzle value is below this, which is what lets `find_pair_bitset`
+/// get away with a fixed number of bits.
+pub const BITSET_BOUND: usize = 2048;
+
+/// Finds two numbers in `list` that add up to `target`, marking each number
+/// seen in a fixed-size bitset instead of hashing it. The list doesn't need to
+/// be sorted.
+///
+/// Numbers outside of `0..BITSET_BOUND` can't be marked, so they're skipped.
+pub fn find_pair_bitset(list: &[i32], target: i32) -> Option<(i32, i32)> {
+    let mut seen = [0u64; BITSET_BOUND / 64];
+    let bit = |n: i32| usize::try_from(n).ok().filter(|n| *n < BITSET_BOUND);
+
+    for n in list.iter() {
+        let n_bit = match bit(*n) {
+            Some(n_bit) => n_bit,
+            None => continue,
+        };
+
+        if let Some(complement) = bit(target - n) {
+            if seen[complement / 64] & (1 << (complement % 64)) != 0 {
+                return Some((target - n, *n));
+            }
+        }
+
+        seen[n_bit / 64] |= 1 << (n_bit % 64);
+    }
+
+    None
+}
+
+/// Finds three numbers in `sorted` that add up to `target` and multiplies
+/// them, searching from each first number in parallel. Whichever thread finds
+/// a combination first wins, so with several to choose from any of them may
+/// be used.
+///
+/// Like `find_pair_sorted`, the slice **must** be sorted in ascending order.
+#[cfg(feature = "rayon")]
+pub fn find_sum_product_par(sorted: &[i32], target: i32) -> Option<i64> {
+    (0..sorted.len()).into_par_iter().find_map_any(|i| {
+        let a = sorted[i];
+        let (b, c) = find_pair_sorted(&sorted[i + 1..], target - a)?;
+        Some(i64::from(a) * i64::from(b) * i64::from(c))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn find_pair_hashed(list: &[i32], target: i32) -> Option<(i32, i32)> {
+        let mut seen = HashSet::new();
+        for n in list.iter() {
+            if seen.contains(&(target - n)) {
+                return Some((target - n, *n));
+            }
+            seen.insert(*n);
+        }
+        None
+    }
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        let report = "1721\n979\n366\n299\n675\n1456\n";
+
+        assert_eq!(Day01::part1(report)?, 514579);
+        assert_eq!(Day01::part2(report)?, 241861950);
+        Ok(())
+    }
+
+    #[test]
+    fn find_pair_sorted_test() {
+        let mut list = vec![1721, 979, 366, 299, 675, 1456];
+        list.sort();
+
+        assert_eq!(find_pair_sorted(&list, 2020), Some((299, 1721)));
+        assert_eq!(find_pair_sorted(&list, 5), None);
+        assert_eq!(find_pair_sorted(&[], 2020), None);
+    }
+
+    #[test]
+    fn find_pair_bitset_test() {
+        let list = [1721, 979, 366, 299, 675, 1456];
+
+        assert_eq!(find_pair_bitset(&list, 2020), Some((1721, 299)));
+        assert_eq!(find_pair_bitset(&list, 5), None);
+        assert_eq!(find_pair_bitset(&[], 2020), None);
+    }
+
+    #[test]
+    fn find_pair_bitset_out_of_bounds() {
+        assert_eq!(find_pair_bitset(&[3000, -980, 1000], 2020), None);
+        assert_eq!(find_pair_bitset(&[5000, 20, -3000], 2000), None);
+        assert_eq!(find_pair_bitset(&[2047, 2047], 4094), Some((2047, 2047)));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn find_sum_product_par_test() {
+        // only 500 + 700 + 820 makes 2020, everything else is far too big
+        let mut list = (1100..2020)
+            .chain(vec![500, 700, 820])
+            .collect::<Vec<i32>>();
+        list.sort();
+
+        let (a, b, c) = find_2020(&list).unwrap();
+        assert_eq!(
+            find_sum_product_par(&list, 2020),
+            Some(i64::from(a) * i64::from(b) * i64::from(c))
+        );
+
+        assert_eq!(find_sum_product_par(&list, 1000), None);
+        assert_eq!(find_sum_product_par(&[], 2020), None);
+    }
+
+    /// Expense reports under 2020, along with a target made from two more of
+    /// them, so some lists have a pair summing to it and some don't.
+    fn report_and_target() -> impl Strategy<Value = (Vec<i32>, i32)> {
+        (
+            prop::collection::vec(0..2020i32, 0..20),
+            0..2020i32,
+            0..2020i32,
+        )
+            .prop_map(|(list, a, b)| (list, a + b))
+    }
+
+    proptest! {
+        #[test]
+        fn find_pair_sorted_matches_hashed((mut list, target) in report_and_target()) {
+            list.sort();
+
+            let sorted = find_pair_sorted(&list, target);
+            let hashed = find_pair_hashed(&list, target);
+
+            prop_assert_eq!(sorted.is_some(), hashed.is_some());
+            if let Some((a, b)) = sorted {
+                prop_assert_eq!(a + b, target);
+            }
+        }
+
+        #[test]
+        fn find_pair_bitset_matches_brute_force((list, target) in report_and_target()) {
+            let brute_force = (0..list.len())
+                .flat_map(|i| (i + 1..list.len()).map(move |j| (i, j)))
+                .any(|(i, j)| list[i] + list[j] == target);
+            let bitset = find_pair_bitset(&list, target);
+
+            prop_assert_eq!(bitset.is_some(), brute_force);
+            if let Some((a, b)) = bitset {
+                prop_assert_eq!(a + b, target);
+            }
+        }
+    }
+}
@@ -0,0 +1,649 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use logos::Lexer;
+use logos::Logos;
+use thiserror::Error;
+
+use crate::{line_col, AocError, Day, Solver};
+
+/// Day 2: Password Philosophy.
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Output = usize;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        Ok(summarize(input).valid_by_count)
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        Ok(summarize(input).valid_by_position)
+    }
+}
+
+impl Day for Day02 {
+    const DAY: u8 = 2;
+}
+
+#[derive(Logos, Debug, PartialEq)]
+enum PasswordRuleToken<'a> {
+    #[regex("[0-9]+", |lex| lex.slice().parse())]
+    Number(u64),
+
+    #[token("-")]
+    Dash,
+
+    #[regex(r"\p{L}:", |lex| lex.slice().chars().next())]
+    TargetCharacter(char),
+
+    #[regex(r"\p{L}+", |lex| lex.slice())]
+    Password(&'a str),
+
+    // Rules are line-oriented, so line breaks are kept around to let the
+    // parser recover at the next line after a malformed rule.
+    #[token("\n")]
+    LineEnd,
+
+    // Logos requires one token variant to handle errors,
+    // it can be named anything you wish.
+    #[error]
+    // We can also use this variant to define whitespace,
+    // or any other matches we wish to skip.
+    #[regex(r"[ \t\r\f]+", logos::skip)]
+    Error,
+}
+
+/// Which interpretation of the rule's two numbers to validate against.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Policy {
+    /// Part 1: the numbers are the min and max occurrences of the target.
+    Count,
+    /// Part 2: the numbers are one-based positions, exactly one of which
+    /// must hold the target.
+    Position,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct PasswordRule<'l> {
+    min_count: usize,
+    max_count: usize,
+    first_spot: usize,
+    second_spot: usize,
+    target_char: char,
+    /// Borrowed from the lexer's source, or owned when built via `FromStr`.
+    password: Cow<'l, str>,
+}
+
+impl<'l> PasswordRule<'l> {
+    fn into_owned(self) -> PasswordRule<'static> {
+        PasswordRule {
+            password: Cow::Owned(self.password.into_owned()),
+            ..self
+        }
+    }
+
+    fn is_valid(&self, policy: Policy) -> bool {
+        match policy {
+            Policy::Count => self.is_valid_count(),
+            Policy::Position => self.is_valid_position(),
+        }
+    }
+
+    /// Part 1: the target character must appear between `min_count` and
+    /// `max_count` times, inclusive.
+    fn is_valid_count(&self) -> bool {
+        let occurrences = self
+            .password
+            .chars()
+            .filter(|c| *c == self.target_char)
+            .count();
+
+        (self.min_count..=self.max_count).contains(&occurrences)
+    }
+
+    /// Part 2: the target character must appear in exactly one of the two
+    /// (zero-based) spots.
+    fn is_valid_position(&self) -> bool {
+        let first_spot = self.password.chars().nth(self.first_spot);
+        let second_spot = self.password.chars().nth(self.second_spot);
+
+        match (first_spot, second_spot) {
+            (Some(x), Some(y)) if x == self.target_char && y == self.target_char => false,
+            (Some(x), _) if x == self.target_char => true,
+            (_, Some(x)) if x == self.target_char => true,
+            _ => false,
+        }
+    }
+}
+
+/// A rule that couldn't be parsed, with the one-based line and column of the
+/// offending token.
+#[derive(Debug, PartialEq, Eq, Error)]
+#[error("{line}:{column}: {message}")]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl<'l> fmt::Display for PasswordRule<'l> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}-{} {}: {}",
+            self.first_spot + 1,
+            self.second_spot + 1,
+            self.target_char,
+            self.password
+        )
+    }
+}
+
+impl FromStr for PasswordRule<'static> {
+    type Err = ParseError;
+
+    /// Parses a single rule, like `1-3 a: abcde`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lexer = PasswordRuleToken::lexer(s);
+        let mut parser = Parser::new(&mut lexer);
+
+        let rule = parser.parse_rule()?;
+
+        if !parser.is_finished() {
+            parser.next_token();
+            return Err(parser.error("Expected only a single rule!"));
+        }
+
+        Ok(rule.into_owned())
+    }
+}
+
+struct Parser<'p, 'l: 'p> {
+    lexer: &'p mut Lexer<'l, PasswordRuleToken<'l>>,
+    line_ended: bool,
+    /// Byte offset of the most recently consumed token.
+    offset: usize,
+}
+
+impl<'p, 'l: 'p> Parser<'p, 'l> {
+    fn new(lexer: &'p mut Lexer<'l, PasswordRuleToken<'l>>) -> Self {
+        Self {
+            lexer,
+            line_ended: true,
+            offset: 0,
+        }
+    }
+
+    fn next_token(&mut self) -> Option<PasswordRuleToken<'l>> {
+        let token = self.lexer.next();
+
+        self.offset = match token {
+            None => self.lexer.source().len(),
+            Some(_) => self.lexer.span().start,
+        };
+
+        self.line_ended = matches!(token, None | Some(PasswordRuleToken::LineEnd));
+        token
+    }
+
+    /// Builds an error pointing at the most recently consumed token.
+    fn error(&self, message: &str) -> ParseError {
+        let (line, column) = line_col(self.lexer.source(), self.offset);
+
+        ParseError {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    /// Are there any rules left to parse? Only whitespace remaining counts as
+    /// finished.
+    fn is_finished(&self) -> bool {
+        self.lexer.remainder().trim().is_empty()
+    }
+
+    /// Throw away the rest of the current line, so parsing can resume with
+    /// the next rule after an error.
+    fn skip_line(&mut self) {
+        while !self.line_ended {
+            self.next_token();
+        }
+    }
+
+    fn parse_rule(&mut self) -> Result<PasswordRule<'l>, ParseError> {
+        let mut token = self.next_token();
+        while let Some(PasswordRuleToken::LineEnd) = token {
+            // blank lines between rules are harmless
+            token = self.next_token();
+        }
+
+        let first_number = match token {
+            Some(PasswordRuleToken::Number(0)) => {
+                return Err(self.error("Rule numbers are one-based, so can't be 0!"))
+            }
+            Some(PasswordRuleToken::Number(n)) => n as usize,
+            _ => return Err(self.error("Expected the first password rule number!")),
+        };
+
+        if let Some(PasswordRuleToken::Dash) = self.next_token() {
+            // good parse
+        } else {
+            return Err(self.error("Expected the dash!"));
+        };
+
+        let second_number = match self.next_token() {
+            Some(PasswordRuleToken::Number(0)) => {
+                return Err(self.error("Rule numbers are one-based, so can't be 0!"))
+            }
+            Some(PasswordRuleToken::Number(n)) => n as usize,
+            _ => return Err(self.error("Expected the second password rule number!")),
+        };
+
+        let target_char =
+            if let Some(PasswordRuleToken::TargetCharacter(target)) = self.next_token() {
+                target
+            } else {
+                return Err(self.error("Expected the required target character!"));
+            };
+
+        let password = if let Some(PasswordRuleToken::Password(password)) = self.next_token() {
+            password
+        } else {
+            return Err(self.error("Expected the password itself!"));
+        };
+
+        match self.next_token() {
+            None | Some(PasswordRuleToken::LineEnd) => {}
+            _ => return Err(self.error("Expected the end of the line!")),
+        }
+
+        Ok(PasswordRule {
+            min_count: first_number,
+            max_count: second_number,
+            // these numbers represent one-based indexes
+            first_spot: first_number - 1,
+            second_spot: second_number - 1,
+            target_char,
+            password: Cow::Borrowed(password),
+        })
+    }
+}
+
+impl<'p, 'l: 'p> Iterator for Parser<'p, 'l> {
+    type Item = Result<PasswordRule<'l>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_finished() {
+            return None;
+        }
+
+        let result = self.parse_rule();
+        if result.is_err() {
+            self.skip_line();
+        }
+
+        Some(result)
+    }
+}
+
+/// What happened while checking a whole file of rules, so dropped lines
+/// don't go unnoticed.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    total_lines: usize,
+    parsed_rules: usize,
+    valid_by_count: usize,
+    valid_by_position: usize,
+    errors: Vec<ParseError>,
+}
+
+impl Summary {
+    pub fn unparseable_lines(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Parsed {}/{} lines ({} unparseable).",
+            self.parsed_rules,
+            self.total_lines,
+            self.unparseable_lines()
+        )?;
+        writeln!(
+            f,
+            "There were {}/{} valid passwords by count.",
+            self.valid_by_count, self.parsed_rules
+        )?;
+        write!(
+            f,
+            "There were {}/{} valid passwords by position.",
+            self.valid_by_position, self.parsed_rules
+        )
+    }
+}
+
+pub fn summarize(source: &str) -> Summary {
+    let mut lexer = PasswordRuleToken::lexer(source);
+    let parser = Parser::new(&mut lexer);
+
+    let mut summary = Summary {
+        total_lines: source.lines().filter(|l| !l.trim().is_empty()).count(),
+        ..Summary::default()
+    };
+
+    for result in parser {
+        match result {
+            Ok(rule) => {
+                summary.parsed_rules += 1;
+                summary.valid_by_count += rule.is_valid(Policy::Count) as usize;
+                summary.valid_by_position += rule.is_valid(Policy::Position) as usize;
+            }
+            Err(error) => summary.errors.push(error),
+        }
+    }
+
+    summary
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        let rules = "1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n";
+
+        assert_eq!(Day02::part1(rules)?, 2);
+        assert_eq!(Day02::part2(rules)?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn parser_iter_test() {
+        let mut lex = PasswordRuleToken::lexer("1-3 a: abcde\n2-4 b: cdefg\n");
+        let mut parser = Parser::new(&mut lex);
+
+        let rule = parser.next().expect("first rule").expect("valid rule");
+        assert_eq!(rule.target_char, 'a');
+
+        let rule2 = parser.next().expect("second rule").expect("valid rule");
+        assert_eq!(rule2.target_char, 'b');
+
+        assert_eq!(parser.next(), None);
+    }
+
+    #[test]
+    fn parse_error_position_test() {
+        let mut lex = PasswordRuleToken::lexer("1- a: abc");
+        let mut parser = Parser::new(&mut lex);
+
+        let error = parser.parse_rule().unwrap_err();
+
+        assert_eq!(error.line, 1);
+        assert_eq!(error.column, 4);
+        assert_eq!(error.message, "Expected the second password rule number!");
+        assert_eq!(lex.span(), 3..5);
+    }
+
+    #[test]
+    fn parser_recovery_test() {
+        let mut lex = PasswordRuleToken::lexer("1-3 a: abcde\n1-3 b: 77\n2-9 c: ccccccccc\n");
+        let parser = Parser::new(&mut lex);
+
+        let (rules, errors): (Vec<_>, Vec<_>) = parser.partition(Result::is_ok);
+
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].as_ref().unwrap().password, "abcde");
+        assert_eq!(rules[1].as_ref().unwrap().password, "ccccccccc");
+
+        assert_eq!(
+            errors,
+            [Err(ParseError {
+                line: 2,
+                column: 8,
+                message: "Expected the password itself!".to_string()
+            })]
+        );
+    }
+
+    #[test]
+    fn parser_yields_results_test() {
+        let mut lex = PasswordRuleToken::lexer("1-3 a: abcde\nnonsense\n2-9 c: ccccccccc\n");
+        let parser = Parser::new(&mut lex);
+
+        let results = parser.collect::<Vec<Result<PasswordRule, ParseError>>>();
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert_eq!(
+            results[1],
+            Err(ParseError {
+                line: 2,
+                column: 1,
+                message: "Expected the first password rule number!".to_string()
+            })
+        );
+        assert!(results[2].is_ok());
+    }
+
+    #[test]
+    fn parser_recovery_at_line_end_test() {
+        let mut lex = PasswordRuleToken::lexer("1-3 a:\n2-9 c: ccccccccc");
+        let parser = Parser::new(&mut lex);
+
+        let results = parser.collect::<Vec<Result<PasswordRule, ParseError>>>();
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_err());
+        assert_eq!(results[1].as_ref().unwrap().password, "ccccccccc");
+    }
+
+    #[test]
+    fn summarize_test() {
+        let source = "1-3 a: abcde\n1-3 b: cdefg\n\n1-3 b\n2-9 c: ccccccccc\n9 z: zz\n";
+
+        let summary = summarize(source);
+
+        assert_eq!(summary.total_lines, 5);
+        assert_eq!(summary.parsed_rules, 3);
+        assert_eq!(summary.valid_by_count, 2);
+        assert_eq!(summary.valid_by_position, 1);
+        assert_eq!(summary.unparseable_lines(), 2);
+        assert_eq!(summary.errors[0].line, 4);
+        assert_eq!(summary.errors[1].line, 6);
+    }
+
+    #[test]
+    fn sample_policy_counts_test() -> Result<(), ParseError> {
+        let mut lex = PasswordRuleToken::lexer("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n");
+        let parser = Parser::new(&mut lex);
+        let rules = parser.collect::<Result<Vec<PasswordRule>, ParseError>>()?;
+
+        assert_eq!(rules.len(), 3);
+
+        let valid_by_count = rules.iter().filter(|r| r.is_valid(Policy::Count)).count();
+        assert_eq!(valid_by_count, 2);
+
+        let valid_by_position = rules
+            .iter()
+            .filter(|r| r.is_valid(Policy::Position))
+            .count();
+        assert_eq!(valid_by_position, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn validity_policies_test() -> Result<(), ParseError> {
+        let mut lex = PasswordRuleToken::lexer("1-3 a: abcde\n1-3 b: cdefg\n");
+        let mut parser = Parser::new(&mut lex);
+
+        let rule = parser.parse_rule()?;
+        assert!(rule.is_valid_count());
+        assert!(rule.is_valid_position());
+
+        let rule2 = parser.parse_rule()?;
+        assert!(!rule2.is_valid_count());
+        assert!(!rule2.is_valid_position());
+        Ok(())
+    }
+
+    #[test]
+    fn display_round_trip_test() -> Result<(), ParseError> {
+        let source = "1-3 a: abcde";
+        let mut lex = PasswordRuleToken::lexer(source);
+        let mut parser = Parser::new(&mut lex);
+
+        let rule = parser.parse_rule()?;
+
+        assert_eq!(rule.to_string(), source);
+        Ok(())
+    }
+
+    #[test]
+    fn from_str_test() -> Result<(), ParseError> {
+        let rule = "1-3 a: abcde".parse::<PasswordRule>()?;
+
+        assert_eq!(rule.min_count, 1);
+        assert_eq!(rule.max_count, 3);
+        assert_eq!(rule.target_char, 'a');
+        assert_eq!(rule.password, "abcde");
+        Ok(())
+    }
+
+    #[test]
+    fn from_str_malformed_test() {
+        let error = "1-3 abcde".parse::<PasswordRule>().unwrap_err();
+        assert_eq!(error.message, "Expected the required target character!");
+        assert_eq!((error.line, error.column), (1, 5));
+
+        let error = "1-3 a:".parse::<PasswordRule>().unwrap_err();
+        assert_eq!(error.message, "Expected the password itself!");
+
+        let error = "1-3 a: abcde fgh".parse::<PasswordRule>().unwrap_err();
+        assert_eq!(error.message, "Expected the end of the line!");
+        assert_eq!((error.line, error.column), (1, 14));
+
+        let error = "1-3 a: abcde\n2-4 b: cdefg"
+            .parse::<PasswordRule>()
+            .unwrap_err();
+        assert_eq!(error.message, "Expected only a single rule!");
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn multibyte_password_test() -> Result<(), ParseError> {
+        let rule = "2-4 é: aébcd".parse::<PasswordRule>()?;
+
+        assert_eq!(rule.target_char, 'é');
+        assert_eq!(rule.password, "aébcd");
+        assert!(rule.is_valid(Policy::Position));
+        assert!(!rule.is_valid(Policy::Count));
+
+        let rule = "1-2 é: aébcd".parse::<PasswordRule>()?;
+        assert!(rule.is_valid(Policy::Position));
+
+        let rule = "2-3 é: aéécd".parse::<PasswordRule>()?;
+        assert!(!rule.is_valid(Policy::Position));
+        assert!(rule.is_valid(Policy::Count));
+        Ok(())
+    }
+
+    #[test]
+    fn multibyte_error_column_test() {
+        let error = "1-3 é: éé 7".parse::<PasswordRule>().unwrap_err();
+
+        assert_eq!(error.message, "Expected the end of the line!");
+        assert_eq!((error.line, error.column), (1, 11));
+    }
+
+    #[test]
+    fn zero_rule_number_test() {
+        let error = "0-3 a: abc".parse::<PasswordRule>().unwrap_err();
+        assert_eq!(error.message, "Rule numbers are one-based, so can't be 0!");
+        assert_eq!((error.line, error.column), (1, 1));
+
+        let error = "1-0 a: abc".parse::<PasswordRule>().unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+
+        let summary = summarize("0-3 a: abc\n1-3 a: abc\n");
+        assert_eq!(summary.parsed_rules, 1);
+        assert_eq!(summary.unparseable_lines(), 1);
+    }
+
+    #[test]
+    fn basic_password_rule_test() -> Result<(), ParseError> {
+        let mut lex = PasswordRuleToken::lexer("1-3 a: abcde\n2-4 b: cdefg\n");
+        let mut parser = Parser::new(&mut lex);
+
+        let rule = parser.parse_rule()?;
+
+        assert_eq!(rule.min_count, 1);
+        assert_eq!(rule.max_count, 3);
+        assert_eq!(rule.first_spot, 0);
+        assert_eq!(rule.second_spot, 2);
+        assert_eq!(rule.target_char, 'a');
+        assert_eq!(rule.password, "abcde");
+
+        let rule2 = parser.parse_rule()?;
+
+        assert_eq!(rule2.min_count, 2);
+        assert_eq!(rule2.max_count, 4);
+        assert_eq!(rule2.first_spot, 1);
+        assert_eq!(rule2.second_spot, 3);
+        assert_eq!(rule2.target_char, 'b');
+        assert_eq!(rule2.password, "cdefg");
+        Ok(())
+    }
+
+    #[test]
+    fn basic_lexing_test() {
+        let mut lex = PasswordRuleToken::lexer("1-3 a: abcde");
+
+        assert_eq!(lex.next(), Some(PasswordRuleToken::Number(1)));
+        assert_eq!(lex.span(), 0..1);
+        assert_eq!(lex.slice(), "1");
+
+        assert_eq!(lex.next(), Some(PasswordRuleToken::Dash));
+        assert_eq!(lex.span(), 1..2);
+        assert_eq!(lex.slice(), "-");
+
+        assert_eq!(lex.next(), Some(PasswordRuleToken::Number(3)));
+        assert_eq!(lex.span(), 2..3);
+        assert_eq!(lex.slice(), "3");
+
+        assert_eq!(lex.next(), Some(PasswordRuleToken::TargetCharacter('a')));
+        assert_eq!(lex.span(), 4..6);
+        assert_eq!(lex.slice(), "a:");
+
+        assert_eq!(lex.next(), Some(PasswordRuleToken::Password("abcde")));
+        assert_eq!(lex.span(), 7..12);
+        assert_eq!(lex.slice(), "abcde");
+    }
+
+    #[test]
+    fn second_basic_lexing_test() {
+        let lex = PasswordRuleToken::lexer("1-3 b: cdefg");
+
+        let tokens = lex.collect::<Vec<PasswordRuleToken>>();
+        assert_eq!(
+            tokens,
+            vec![
+                PasswordRuleToken::Number(1),
+                PasswordRuleToken::Dash,
+                PasswordRuleToken::Number(3),
+                PasswordRuleToken::TargetCharacter('b'),
+                PasswordRuleToken::Password("cdefg")
+            ]
+        );
+    }
+}
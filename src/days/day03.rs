@@ -0,0 +1,671 @@
+use std::fmt;
+use std::str::FromStr;
+
+use logos::{Lexer, Logos};
+use thiserror::Error;
+
+use crate::grid::Grid;
+use crate::{AocError, Day, Solver};
+
+/// Day 3: Toboggan Trajectory.
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Output = usize;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        let map = parse_map(input)?;
+        Ok(map.count_trees_on_slope(3, 1))
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        let map = parse_map(input)?;
+        Ok(tree_product(
+            &map,
+            &[(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)],
+        ))
+    }
+}
+
+impl Day for Day03 {
+    const DAY: u8 = 3;
+}
+
+fn parse_map(input: &str) -> Result<Map, AocError> {
+    input
+        .parse::<Map>()
+        .map_err(|e| AocError::Parse(e.to_string()))
+}
+
+#[derive(Logos, Debug, PartialEq, Clone, Copy)]
+pub enum Tile {
+    #[token(".")]
+    Open,
+
+    #[token("#")]
+    Tree,
+
+    #[token("\n")]
+    RowEnd,
+
+    // Logos requires one token variant to handle errors,
+    // it can be named anything you wish.
+    #[error]
+    // We can also use this variant to define whitespace,
+    // or any other matches we wish to skip.
+    #[regex(r"[ \t\f]+", logos::skip)]
+    Error,
+}
+
+impl Tile {
+    /// How the tile is drawn on a map.
+    fn symbol(&self) -> char {
+        match self {
+            Tile::Tree => '#',
+            _ => '.',
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum MapError {
+    /// A row (zero-indexed) didn't match the width of the first row.
+    #[error("Row {row} has {found} tiles, but the map is {expected} tiles wide!")]
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+/// How `Map::tile_at` treats coordinates beyond the edges of the map.
+/// Horizontal coordinates always wrap.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum WrapMode {
+    /// Anything below the bottom row is off the map.
+    #[default]
+    None,
+    /// The map also repeats downward, like a torus. Only `tile_at` sees the
+    /// repeats: toboggan paths still end after the last row, since they'd
+    /// never reach the bottom otherwise.
+    Vertical,
+}
+
+#[derive(Debug)]
+pub struct Map {
+    tiles: Grid<Tile>,
+    wrap: WrapMode,
+}
+
+impl Map {
+    /// Parses the whole source behind `tokens`, the same as `str::parse`.
+    pub fn parse(tokens: &mut Lexer<Tile>) -> Result<Self, MapError> {
+        tokens.source().parse()
+    }
+
+    /// Switches how `tile_at` treats rows past the bottom of the map.
+    pub fn with_wrap(self, wrap: WrapMode) -> Self {
+        Self { wrap, ..self }
+    }
+
+    fn width(&self) -> usize {
+        self.tiles.width()
+    }
+
+    fn height(&self) -> usize {
+        self.tiles.height()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width() == 0 || self.height() == 0
+    }
+
+    /// The Map's origin is at the top left. Zero indexed.
+    fn tile_at(&self, x: usize, y: usize) -> Option<Tile> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let y = match self.wrap {
+            WrapMode::None => y,
+            WrapMode::Vertical => y % self.height(),
+        };
+
+        self.tiles.get(x % self.width(), y).copied()
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[Tile]> {
+        self.tiles.row(y)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[Tile]> {
+        self.tiles.rows()
+    }
+
+    fn toboggan_path(&self, course: &mut impl Iterator<Item = (usize, usize)>) -> Vec<Tile> {
+        course
+            .take_while(|&(_, y)| y < self.height())
+            .map(|(x, y)| self.tile_at(x, y))
+            .skip(1)
+            .take_while(Option::is_some)
+            .flatten()
+            .collect()
+    }
+
+    /// Like `toboggan_path`, but keeps the `(x, y)` of each visited tile.
+    /// The starting position isn't included, and the path ends once it runs
+    /// off the bottom of the map.
+    pub fn path_coords(
+        &self,
+        dx: usize,
+        dy: usize,
+    ) -> impl Iterator<Item = ((usize, usize), Tile)> + '_ {
+        build_slope(dx, dy)
+            .skip(1)
+            .take_while(move |&(_, y)| y < self.height())
+            .map(move |(x, y)| self.tile_at(x, y).map(|tile| ((x, y), tile)))
+            .take_while(Option::is_some)
+            .flatten()
+    }
+
+    /// Renders the map with every tile visited on the `(dx, dy)` slope marked,
+    /// `O` where it's open and `X` where a tree was hit.
+    pub fn render_path(&self, dx: usize, dy: usize) -> String {
+        let mut rows = self
+            .rows()
+            .map(|row| row.iter().map(|tile| tile.symbol()).collect::<Vec<char>>())
+            .collect::<Vec<_>>();
+
+        for ((x, y), tile) in self.path_coords(dx, dy) {
+            rows[y][x % self.width()] = match tile {
+                Tile::Tree => 'X',
+                _ => 'O',
+            };
+        }
+
+        rows.into_iter()
+            .map(|row| row.into_iter().collect::<String>() + "\n")
+            .collect()
+    }
+
+    /// Rides down a straight slope beginning at `start` instead of the top
+    /// left corner.
+    fn toboggan_path_from(&self, start: (usize, usize), dx: usize, dy: usize) -> Vec<Tile> {
+        self.toboggan_path(&mut build_slope_from(start, dx, dy))
+    }
+
+    fn count_trees_on_slope(&self, dx: usize, dy: usize) -> usize {
+        count_trees(&self.toboggan_path_from((0, 0), dx, dy))
+    }
+
+    #[allow(dead_code)]
+    fn view_map(&self) {
+        for (i, tile) in self.tiles.cells().iter().enumerate() {
+            print!("{:?}({:02}), ", tile, i);
+            if (i + 1) % self.width() == 0 {
+                println!()
+            }
+        }
+    }
+}
+
+impl fmt::Display for Map {
+    /// Renders the map as it appeared in the input, one row per line.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for tile in row {
+                write!(f, "{}", tile.symbol())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Map {
+    type Err = MapError;
+
+    /// Takes the width from the first row, and every other row has to match.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tiles = Vec::new();
+        let mut height = 0;
+        let mut row_width = 0;
+        // the first row sets the width every other row has to match
+        let mut width = None;
+
+        let mut check_row = |row: usize, found: usize| match width {
+            None => {
+                width = Some(found);
+                Ok(())
+            }
+            Some(expected) if expected == found => Ok(()),
+            Some(expected) => Err(MapError::Ragged {
+                row,
+                expected,
+                found,
+            }),
+        };
+
+        for token in Tile::lexer(s) {
+            match token {
+                Tile::Open | Tile::Tree => {
+                    tiles.push(token);
+                    row_width += 1;
+                }
+                // blank lines aren't rows, just like `Grid::parse_with`
+                Tile::RowEnd if row_width == 0 => {}
+                Tile::RowEnd => {
+                    check_row(height, row_width)?;
+                    height += 1;
+                    row_width = 0;
+                }
+                Tile::Error => {}
+            }
+        }
+
+        // the last row may not have a trailing newline
+        if row_width > 0 {
+            check_row(height, row_width)?;
+            height += 1;
+        }
+
+        Ok(Self {
+            tiles: Grid::from_cells(tiles, width.unwrap_or(0), height),
+            wrap: WrapMode::default(),
+        })
+    }
+}
+
+fn build_slope(delta_x: usize, delta_y: usize) -> impl Iterator<Item = (usize, usize)> {
+    build_slope_from((0, 0), delta_x, delta_y)
+}
+
+fn build_slope_from(
+    (start_x, start_y): (usize, usize),
+    delta_x: usize,
+    delta_y: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    let x = std::iter::successors(Some(start_x), move |n| Some(n + delta_x));
+    let y = std::iter::successors(Some(start_y), move |n| Some(n + delta_y));
+
+    x.zip(y)
+}
+
+fn count_trees(tiles: &[Tile]) -> usize {
+    tiles.iter().filter(|tile| **tile == Tile::Tree).count()
+}
+
+/// Multiplies together the number of trees hit on each `(dx, dy)` slope.
+pub fn tree_product(map: &Map, slopes: &[(usize, usize)]) -> usize {
+    slopes
+        .iter()
+        .map(|(dx, dy)| map.count_trees_on_slope(*dx, *dy))
+        .product()
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const SAMPLE_MAP: &str = "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+";
+
+    #[test]
+    fn day_test() -> Result<(), AocError> {
+        assert_eq!(Day03::DAY, 3);
+        assert!(!Day03::default_input()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day03::part1(SAMPLE_MAP)?, 7);
+        assert_eq!(Day03::part2(SAMPLE_MAP)?, 336);
+        Ok(())
+    }
+
+    #[test]
+    fn count_trees_on_slope_test() -> Result<(), MapError> {
+        let map = SAMPLE_MAP.parse::<Map>()?;
+
+        assert_eq!(map.count_trees_on_slope(3, 1), 7);
+        assert_eq!(map.count_trees_on_slope(1, 2), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn toboggan_path_test() {
+        let mut lex = Tile::lexer(
+            "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+",
+        );
+        let map = Map::parse(&mut lex).unwrap();
+
+        assert_eq!(map.height(), 11);
+        assert_eq!(map.width(), 11);
+
+        let expected = vec![
+            Tile::Open,
+            Tile::Tree,
+            Tile::Open,
+            Tile::Tree,
+            Tile::Tree,
+            Tile::Open,
+            Tile::Tree,
+            Tile::Tree,
+            Tile::Tree,
+            Tile::Tree,
+        ];
+
+        let actual = map.toboggan_path(&mut build_slope(3, 1));
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn tree_product_test() {
+        let mut lex = Tile::lexer(
+            "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+",
+        );
+        let map = Map::parse(&mut lex).unwrap();
+
+        let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+        assert_eq!(tree_product(&map, &slopes), 336);
+    }
+
+    #[test]
+    fn map_access() {
+        let mut lex = Tile::lexer(".#.\n#.#\n..#");
+        let map = Map::parse(&mut lex).unwrap();
+
+        assert_eq!(Some(Tile::Open), map.tile_at(0, 0));
+        assert_eq!(Some(Tile::Tree), map.tile_at(1, 0));
+        assert_eq!(Some(Tile::Open), map.tile_at(2, 0));
+
+        assert_eq!(Some(Tile::Tree), map.tile_at(0, 1));
+        assert_eq!(Some(Tile::Open), map.tile_at(1, 1));
+        assert_eq!(Some(Tile::Tree), map.tile_at(2, 1));
+
+        assert_eq!(Some(Tile::Open), map.tile_at(0, 2));
+        assert_eq!(Some(Tile::Open), map.tile_at(1, 2));
+        assert_eq!(Some(Tile::Tree), map.tile_at(2, 2));
+
+        // out of bounds beyond the height of the map
+        assert_eq!(None, map.tile_at(0, 3));
+        assert_eq!(None, map.tile_at(0, 4));
+
+        // out of bounds beyond the width of the map -- should wrap!
+        assert_eq!(Some(Tile::Open), map.tile_at(3, 0));
+        assert_eq!(Some(Tile::Tree), map.tile_at(4, 0));
+        assert_eq!(Some(Tile::Open), map.tile_at(5, 0));
+
+        assert_eq!(Some(Tile::Open), map.tile_at(6, 0));
+        assert_eq!(Some(Tile::Tree), map.tile_at(7, 0));
+        assert_eq!(Some(Tile::Open), map.tile_at(8, 0));
+
+        assert_eq!(Some(Tile::Open), map.tile_at(9, 0));
+        assert_eq!(Some(Tile::Tree), map.tile_at(10, 0));
+        assert_eq!(Some(Tile::Open), map.tile_at(11, 0));
+
+        assert_eq!(Some(Tile::Tree), map.tile_at(3, 1));
+        assert_eq!(Some(Tile::Open), map.tile_at(4, 1));
+        assert_eq!(Some(Tile::Tree), map.tile_at(5, 1));
+
+        assert_eq!(Some(Tile::Open), map.tile_at(3, 2));
+        assert_eq!(Some(Tile::Open), map.tile_at(4, 2));
+        assert_eq!(Some(Tile::Tree), map.tile_at(5, 2));
+    }
+
+    #[test]
+    fn map_parsing() {
+        let mut lex = Tile::lexer(".#.\n#.#\n..#");
+        let map = Map::parse(&mut lex).unwrap();
+
+        assert_eq!(map.height(), 3);
+        assert_eq!(map.width(), 3);
+        assert_eq!(map.tiles.cells().len(), 9);
+    }
+
+    #[test]
+    fn large_map_parsing() -> Result<(), MapError> {
+        let (width, height) = (173, 311);
+        let source = (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| if (x * 7 + y * 3) % 5 == 0 { '#' } else { '.' })
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect::<String>();
+
+        let map = source.parse::<Map>()?;
+
+        assert_eq!(map.width(), width);
+        assert_eq!(map.height(), height);
+        assert_eq!(map.tiles.cells().len(), width * height);
+        assert_eq!(map.tile_at(5, 0), Some(Tile::Tree));
+        assert_eq!(map.tile_at(1, 1), Some(Tile::Tree));
+        assert_eq!(map.tile_at(2, 1), Some(Tile::Open));
+        assert_eq!(map.to_string(), source);
+        Ok(())
+    }
+
+    #[test]
+    fn map_from_str() -> Result<(), MapError> {
+        let map = ".#.\n#.#\n..#\n".parse::<Map>()?;
+
+        assert_eq!(map.height(), 3);
+        assert_eq!(map.width(), 3);
+        assert_eq!(Some(Tile::Tree), map.tile_at(2, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn map_display_round_trip() -> Result<(), MapError> {
+        let source = "..##...\n#...#..\n.#....#\n";
+        let map = source.parse::<Map>()?;
+
+        assert_eq!(map.to_string(), source);
+        Ok(())
+    }
+
+    #[test]
+    fn sample_map_snapshot() -> Result<(), MapError> {
+        let map = SAMPLE_MAP.parse::<Map>()?;
+
+        insta::assert_snapshot!(map.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn sample_path_snapshot() -> Result<(), MapError> {
+        let map = SAMPLE_MAP.parse::<Map>()?;
+
+        insta::assert_snapshot!(map.render_path(3, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn path_coords_test() -> Result<(), MapError> {
+        let map = SAMPLE_MAP.parse::<Map>()?;
+
+        let path = map.path_coords(3, 1).take(4).collect::<Vec<_>>();
+
+        assert_eq!(
+            path,
+            vec![
+                ((3, 1), Tile::Open),
+                ((6, 2), Tile::Tree),
+                ((9, 3), Tile::Open),
+                ((12, 4), Tile::Tree),
+            ]
+        );
+
+        // stops at the bottom of the map
+        assert_eq!(map.path_coords(3, 1).count(), 10);
+        assert_eq!(
+            map.path_coords(1, 2).last().map(|(pos, _)| pos),
+            Some((5, 10))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn toboggan_path_from_test() -> Result<(), MapError> {
+        let map = SAMPLE_MAP.parse::<Map>()?;
+
+        let from_origin = map.toboggan_path_from((0, 0), 3, 1);
+        assert_eq!(from_origin, map.toboggan_path(&mut build_slope(3, 1)));
+
+        let from_offset = map.toboggan_path_from((2, 0), 3, 1);
+        assert_ne!(from_offset, from_origin);
+        assert_eq!(from_offset.len(), 10);
+        assert_eq!(&from_offset[..3], &[Tile::Open, Tile::Open, Tile::Open]);
+        assert_eq!(count_trees(&from_offset), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn wrap_modes() -> Result<(), MapError> {
+        let map = ".#.\n#.#\n..#".parse::<Map>()?;
+        assert_eq!(map.wrap, WrapMode::None);
+        assert_eq!(None, map.tile_at(0, map.height()));
+
+        let map = map.with_wrap(WrapMode::Vertical);
+        assert_eq!(Some(Tile::Open), map.tile_at(0, map.height()));
+        assert_eq!(Some(Tile::Tree), map.tile_at(0, map.height() + 1));
+        assert_eq!(Some(Tile::Tree), map.tile_at(5, 8));
+
+        // paths still end at the bottom row instead of going round forever
+        assert_eq!(map.count_trees_on_slope(1, 1), 1);
+        assert_eq!(map.path_coords(1, 1).count(), 2);
+        assert_eq!(map.render_path(1, 1), ".#.\n#O#\n..X\n");
+        Ok(())
+    }
+
+    #[test]
+    fn map_rows() -> Result<(), MapError> {
+        let map = ".#.\n#.#\n..#".parse::<Map>()?;
+
+        assert_eq!(map.row(1), Some(&[Tile::Tree, Tile::Open, Tile::Tree][..]));
+        assert_eq!(map.row(3), None);
+        assert_eq!(map.rows().count(), map.height());
+        Ok(())
+    }
+
+    #[test]
+    fn empty_map() -> Result<(), MapError> {
+        let map = "".parse::<Map>()?;
+
+        assert!(map.is_empty());
+        assert_eq!(None, map.tile_at(0, 0));
+        assert!(map.toboggan_path(&mut build_slope(3, 1)).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn blank_lines_are_skipped() -> Result<(), MapError> {
+        let map = format!("{}\n", SAMPLE_MAP).parse::<Map>()?;
+
+        assert_eq!(map.height(), 11);
+        assert_eq!(map.width(), 11);
+        assert_eq!(map.to_string(), SAMPLE_MAP);
+        assert_eq!(Day03::part1(&format!("{}\n", SAMPLE_MAP)).unwrap(), 7);
+
+        let map = ".#.\n\n#.#\n".parse::<Map>()?;
+        assert_eq!(map.height(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn ragged_map_from_str() {
+        let result = ".#.\n#.\n..#".parse::<Map>();
+
+        assert_eq!(
+            result.unwrap_err(),
+            MapError::Ragged {
+                row: 1,
+                expected: 3,
+                found: 2
+            }
+        );
+    }
+
+    #[test]
+    fn tile_lexing_test() {
+        let mut lex = Tile::lexer("..##..\n.#..");
+
+        assert_eq!(lex.next(), Some(Tile::Open));
+        assert_eq!(lex.next(), Some(Tile::Open));
+        assert_eq!(lex.next(), Some(Tile::Tree));
+        assert_eq!(lex.next(), Some(Tile::Tree));
+        assert_eq!(lex.next(), Some(Tile::Open));
+        assert_eq!(lex.next(), Some(Tile::Open));
+        assert_eq!(lex.next(), Some(Tile::RowEnd));
+        assert_eq!(lex.next(), Some(Tile::Open));
+        assert_eq!(lex.next(), Some(Tile::Tree));
+        assert_eq!(lex.next(), Some(Tile::Open));
+        assert_eq!(lex.next(), Some(Tile::Open));
+    }
+
+    /// Rows of `.` and `#` that are all the same width.
+    fn map_source() -> impl Strategy<Value = String> {
+        (1..40usize).prop_flat_map(|width| {
+            let row = prop::collection::vec(prop::bool::ANY, width).prop_map(|row| {
+                row.into_iter()
+                    .map(|tree| if tree { '#' } else { '.' })
+                    .collect::<String>()
+            });
+            prop::collection::vec(row, 1..40).prop_map(|rows| rows.join("\n"))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn map_display_round_trips(source in map_source(), trailing_newline in prop::bool::ANY) {
+            let input = if trailing_newline { format!("{}\n", source) } else { source.clone() };
+            let map = input.parse::<Map>().unwrap();
+
+            prop_assert_eq!(map.to_string(), format!("{}\n", source));
+            prop_assert_eq!(map.height(), source.lines().count());
+            prop_assert_eq!(map.width(), source.lines().next().unwrap().len());
+        }
+    }
+}
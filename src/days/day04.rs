@@ -0,0 +1,978 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use logos::{Lexer, Logos, Span};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{line_col, AocError, Day, Solver};
+
+/// Day 4: Passport Processing.
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Output = usize;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        Ok(count_passports(input, |passport| passport.is_valid()))
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        Ok(count_passports(input, |passport| {
+            passport.is_valid_strict()
+        }))
+    }
+}
+
+impl Day for Day04 {
+    const DAY: u8 = 4;
+}
+
+fn count_passports(input: &str, valid: impl Fn(&Passport) -> bool) -> usize {
+    let mut lexer = Fact::lexer(input);
+    PassportParser::new(&mut lexer)
+        .filter(|passport| valid(passport))
+        .count()
+}
+
+/// Facts listed on a Passport
+///
+/// * byr (Birth Year)
+/// * cid (Country ID)
+/// * ecl (Eye Color)
+/// * eyr (Expiration Year)
+/// * hcl (Hair Color)
+/// * hgt (Height)
+/// * iyr (Issue Year)
+/// * pid (Passport ID)
+#[derive(Logos, Debug, PartialEq, Clone, Copy)]
+pub enum Fact<'a> {
+    #[regex("byr:([[:alnum:]]+)", fact_value)]
+    BirthYear(&'a str),
+
+    #[regex("cid:([#[:alnum:]]+)", fact_value)]
+    CountryId(&'a str),
+
+    #[regex("ecl:([[:alnum:]]+)", fact_value)]
+    EyeColor(&'a str),
+
+    #[regex("eyr:([[:alnum:]]+)", fact_value)]
+    ExpirationYear(&'a str),
+
+    #[regex("hcl:([#[:alnum:]]+)", fact_value)]
+    HairColor(&'a str),
+
+    #[regex("hgt:([[:alnum:]]+)", fact_value)]
+    Height(&'a str),
+
+    #[regex("iyr:([[:alnum:]]+)", fact_value)]
+    IssueYear(&'a str),
+
+    #[regex("pid:([#[:alnum:]]+)", fact_value)]
+    PassportId(&'a str),
+
+    #[regex("\n\n+")]
+    DocumentEnd,
+
+    #[regex("[^[:space:]]+")]
+    Invalid,
+
+    // Logos requires one token variant to handle errors,
+    // it can be named anything you wish.
+    #[error]
+    // We can also use this variant to define whitespace,
+    // or any other matches we wish to skip.
+    #[regex(r"[ \t\n\f]+", logos::skip)]
+    Error,
+}
+
+const BIRTH_YEARS: RangeInclusive<u16> = 1920..=2002;
+const ISSUE_YEARS: RangeInclusive<u16> = 2010..=2020;
+const EXPIRATION_YEARS: RangeInclusive<u16> = 2020..=2030;
+const HEIGHTS_CM: RangeInclusive<u16> = 150..=193;
+const HEIGHTS_IN: RangeInclusive<u16> = 59..=76;
+
+// Note: callbacks can return `Option` or `Result`
+fn fact_value<'source>(lex: &mut Lexer<'source, Fact<'source>>) -> &'source str {
+    &lex.slice()[4..]
+}
+
+#[derive(Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Passport<'s> {
+    birth_year: Option<&'s str>,
+    country_id: Option<&'s str>,
+    eye_color: Option<&'s str>,
+    expiration_year: Option<&'s str>,
+    hair_color: Option<&'s str>,
+    height: Option<&'s str>,
+    issue_year: Option<&'s str>,
+    passport_id: Option<&'s str>,
+}
+
+/// A `Passport` that owns its values, so it can outlive the source text or be
+/// read back in from JSON.
+#[cfg(feature = "serde")]
+#[derive(Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedPassport {
+    pub birth_year: Option<String>,
+    pub country_id: Option<String>,
+    pub eye_color: Option<String>,
+    pub expiration_year: Option<String>,
+    pub hair_color: Option<String>,
+    pub height: Option<String>,
+    pub issue_year: Option<String>,
+    pub passport_id: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl<'s> From<&Passport<'s>> for OwnedPassport {
+    fn from(passport: &Passport<'s>) -> Self {
+        let owned = |value: Option<&str>| value.map(str::to_string);
+
+        Self {
+            birth_year: owned(passport.birth_year),
+            country_id: owned(passport.country_id),
+            eye_color: owned(passport.eye_color),
+            expiration_year: owned(passport.expiration_year),
+            hair_color: owned(passport.hair_color),
+            height: owned(passport.height),
+            issue_year: owned(passport.issue_year),
+            passport_id: owned(passport.passport_id),
+        }
+    }
+}
+
+impl<'s> Passport<'s> {
+    /// Missing fields are written out as `null`.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Passports are always serializable!")
+    }
+
+    fn is_empty(&self) -> bool {
+        [
+            self.birth_year,
+            self.country_id,
+            self.eye_color,
+            self.expiration_year,
+            self.hair_color,
+            self.height,
+            self.issue_year,
+            self.passport_id,
+        ]
+        .iter()
+        .all(Option::is_none)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        [
+            self.birth_year,
+            // NOT REQUIRED: self.country_id,
+            self.eye_color,
+            self.expiration_year,
+            self.hair_color,
+            self.height,
+            self.issue_year,
+            self.passport_id,
+        ]
+        .iter()
+        .all(Option::is_some)
+    }
+
+    /// Part 2: every required field must be present *and* hold a sensible
+    /// value. Country ID is still ignored.
+    pub fn is_valid_strict(&self) -> bool {
+        self.parsed().is_ok_and(|passport| passport.is_valid())
+    }
+
+    /// Checks every required field, collecting a reason for each one that
+    /// fails rather than stopping at the first.
+    pub fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+
+        report.check(parse_year("byr", self.birth_year), |year| {
+            within("byr", year, BIRTH_YEARS)
+        });
+        report.check(parse_year("iyr", self.issue_year), |year| {
+            within("iyr", year, ISSUE_YEARS)
+        });
+        report.check(parse_year("eyr", self.expiration_year), |year| {
+            within("eyr", year, EXPIRATION_YEARS)
+        });
+        report.check(parse_field("hgt", self.height, parse_height), |height| {
+            if height.is_valid() {
+                Ok(())
+            } else {
+                let reason = format!("out of range {:?}", height.valid_range());
+                Err(invalid("hgt", height, reason))
+            }
+        });
+        report.check(require("hcl", self.hair_color), |color| {
+            if valid_hcl(color) {
+                Ok(())
+            } else {
+                let reason = "is not a # followed by six hex digits".to_string();
+                Err(invalid("hcl", color, reason))
+            }
+        });
+        report.check(
+            parse_field("ecl", self.eye_color, |s| s.parse::<EyeColor>().ok()),
+            |_| Ok(()),
+        );
+        report.check(require("pid", self.passport_id), |id| {
+            if valid_pid(id) {
+                Ok(())
+            } else {
+                Err(invalid("pid", id, "is not nine digits".to_string()))
+            }
+        });
+
+        report
+    }
+
+    /// Parses each required field into a typed value, without checking that
+    /// the values are in range.
+    pub fn parsed(&self) -> Result<ParsedPassport<'s>, ValidationError> {
+        Ok(ParsedPassport {
+            birth_year: parse_year("byr", self.birth_year)?,
+            country_id: self.country_id,
+            eye_color: parse_field("ecl", self.eye_color, |s| s.parse().ok())?,
+            expiration_year: parse_year("eyr", self.expiration_year)?,
+            hair_color: require("hcl", self.hair_color)?,
+            height: parse_field("hgt", self.height, parse_height)?,
+            issue_year: parse_year("iyr", self.issue_year)?,
+            passport_id: require("pid", self.passport_id)?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    /// A required field wasn't on the passport at all.
+    Missing(&'static str),
+    /// A field was present, but its value couldn't be parsed.
+    Unparseable { field: &'static str, value: String },
+    /// A field parsed fine, but doesn't hold an acceptable value.
+    Invalid {
+        field: &'static str,
+        value: String,
+        reason: String,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationError::Missing(field) => write!(f, "{} is missing", field),
+            ValidationError::Unparseable { field, value } => {
+                write!(f, "{} `{}` could not be parsed", field, value)
+            }
+            ValidationError::Invalid {
+                field,
+                value,
+                reason,
+            } => write!(f, "{} {} {}", field, value, reason),
+        }
+    }
+}
+
+/// Every reason a passport failed strict validation, one per bad field.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    pub failures: Vec<ValidationError>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Records a failure if the field didn't parse, or parsed into a value
+    /// that `check` rejects.
+    fn check<T>(
+        &mut self,
+        parsed: Result<T, ValidationError>,
+        check: impl Fn(T) -> Result<(), ValidationError>,
+    ) {
+        if let Err(failure) = parsed.and_then(check) {
+            self.failures.push(failure);
+        }
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for failure in self.failures.iter() {
+            writeln!(f, "{}", failure)?;
+        }
+        Ok(())
+    }
+}
+
+fn invalid(field: &'static str, value: impl fmt::Display, reason: String) -> ValidationError {
+    ValidationError::Invalid {
+        field,
+        value: value.to_string(),
+        reason,
+    }
+}
+
+fn within(
+    field: &'static str,
+    value: u16,
+    range: RangeInclusive<u16>,
+) -> Result<(), ValidationError> {
+    if range.contains(&value) {
+        Ok(())
+    } else {
+        Err(invalid(field, value, format!("out of range {:?}", range)))
+    }
+}
+
+fn require<'s>(field: &'static str, value: Option<&'s str>) -> Result<&'s str, ValidationError> {
+    value.ok_or(ValidationError::Missing(field))
+}
+
+fn parse_field<T>(
+    field: &'static str,
+    value: Option<&str>,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<T, ValidationError> {
+    let value = require(field, value)?;
+
+    parse(value).ok_or_else(|| ValidationError::Unparseable {
+        field,
+        value: value.to_string(),
+    })
+}
+
+/// Years are always written with four digits.
+fn parse_year(field: &'static str, value: Option<&str>) -> Result<u16, ValidationError> {
+    parse_field(field, value, |year| {
+        if year.len() == 4 {
+            year.parse().ok()
+        } else {
+            None
+        }
+    })
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Height {
+    Cm(u16),
+    In(u16),
+}
+
+/// Parses a height like `183cm` or `59in`. Heights without a unit, or with
+/// anything but digits before it, are rejected.
+fn parse_height(s: &str) -> Option<Height> {
+    let (digits, unit): (&str, fn(u16) -> Height) = if let Some(cm) = s.strip_suffix("cm") {
+        (cm, Height::Cm)
+    } else if let Some(inches) = s.strip_suffix("in") {
+        (inches, Height::In)
+    } else {
+        return None;
+    };
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    digits.parse().ok().map(unit)
+}
+
+impl Height {
+    /// Each unit has its own acceptable range.
+    fn is_valid(&self) -> bool {
+        self.valid_range().contains(&self.value())
+    }
+
+    fn value(&self) -> u16 {
+        match self {
+            Height::Cm(cm) => *cm,
+            Height::In(inches) => *inches,
+        }
+    }
+
+    fn valid_range(&self) -> RangeInclusive<u16> {
+        match self {
+            Height::Cm(_) => HEIGHTS_CM,
+            Height::In(_) => HEIGHTS_IN,
+        }
+    }
+}
+
+impl fmt::Display for Height {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Height::Cm(cm) => write!(f, "{}cm", cm),
+            Height::In(inches) => write!(f, "{}in", inches),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EyeColor {
+    Amber,
+    Blue,
+    Brown,
+    Gray,
+    Green,
+    Hazel,
+    Other,
+}
+
+impl FromStr for EyeColor {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "amb" => Ok(EyeColor::Amber),
+            "blu" => Ok(EyeColor::Blue),
+            "brn" => Ok(EyeColor::Brown),
+            "gry" => Ok(EyeColor::Gray),
+            "grn" => Ok(EyeColor::Green),
+            "hzl" => Ok(EyeColor::Hazel),
+            "oth" => Ok(EyeColor::Other),
+            _ => Err(ValidationError::Unparseable {
+                field: "ecl",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Hair colors are a `#` followed by exactly six lowercase hex digits. The
+/// lexer lets through anything alphanumeric, like `#zzz`.
+fn valid_hcl(s: &str) -> bool {
+    s.strip_prefix('#').is_some_and(|hex| {
+        hex.len() == 6 && hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+    })
+}
+
+/// Passport IDs are nine digits, leading zeros included. The lexer also lets
+/// through `#` and letters, like `#12345678`.
+fn valid_pid(s: &str) -> bool {
+    s.len() == 9 && s.chars().all(|c| c.is_ascii_digit())
+}
+
+/// A `Passport` with all of its required fields present and parsed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParsedPassport<'s> {
+    pub birth_year: u16,
+    pub country_id: Option<&'s str>,
+    pub eye_color: EyeColor,
+    pub expiration_year: u16,
+    pub hair_color: &'s str,
+    pub height: Height,
+    pub issue_year: u16,
+    pub passport_id: &'s str,
+}
+
+impl<'s> ParsedPassport<'s> {
+    /// Whether every value is in range. The eye color was already checked
+    /// when it parsed, and the country doesn't matter.
+    pub fn is_valid(&self) -> bool {
+        BIRTH_YEARS.contains(&self.birth_year)
+            && ISSUE_YEARS.contains(&self.issue_year)
+            && EXPIRATION_YEARS.contains(&self.expiration_year)
+            && self.height.is_valid()
+            && valid_hcl(self.hair_color)
+            && valid_pid(self.passport_id)
+    }
+}
+
+pub struct PassportParser<'a, 'source: 'a> {
+    tokens: &'a mut Lexer<'source, Fact<'source>>,
+    diagnostics: Vec<(Span, String)>,
+}
+
+impl<'a, 'source> PassportParser<'a, 'source> {
+    pub fn new(tokens: &'a mut Lexer<'source, Fact<'source>>) -> Self {
+        Self {
+            tokens,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Every unrecognized token skipped so far, and where it was found.
+    pub fn diagnostics(&self) -> &[(Span, String)] {
+        &self.diagnostics
+    }
+
+    /// The one-based line and column where each diagnostic starts.
+    pub fn diagnostic_positions(&self) -> Vec<(usize, usize)> {
+        self.diagnostics
+            .iter()
+            .map(|(span, _)| line_col(self.tokens.source(), span.start))
+            .collect()
+    }
+}
+
+impl<'a, 'source: 'a> Iterator for PassportParser<'a, 'source> {
+    type Item = Passport<'source>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut passport = Passport::default();
+
+        loop {
+            match self.tokens.next() {
+                None | Some(Fact::DocumentEnd) => {
+                    if passport.is_empty() {
+                        return None;
+                    } else {
+                        return Some(passport);
+                    }
+                }
+                Some(Fact::Error) | Some(Fact::Invalid) => {
+                    self.diagnostics.push((
+                        self.tokens.span(),
+                        format!(
+                            "Expected a valid token, but found `{}`",
+                            self.tokens.slice()
+                        ),
+                    ));
+                }
+                Some(Fact::BirthYear(year)) => {
+                    passport.birth_year = Some(year);
+                }
+                Some(Fact::CountryId(id)) => {
+                    passport.country_id = Some(id);
+                }
+                Some(Fact::EyeColor(color)) => {
+                    passport.eye_color = Some(color);
+                }
+                Some(Fact::ExpirationYear(year)) => {
+                    passport.expiration_year = Some(year);
+                }
+                Some(Fact::HairColor(color)) => {
+                    passport.hair_color = Some(color);
+                }
+                Some(Fact::Height(measurement)) => {
+                    passport.height = Some(measurement);
+                }
+                Some(Fact::IssueYear(year)) => {
+                    passport.issue_year = Some(year);
+                }
+                Some(Fact::PassportId(id)) => {
+                    passport.passport_id = Some(id);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn day_test() -> Result<(), AocError> {
+        assert_eq!(Day04::DAY, 4);
+        assert!(!Day04::default_input()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        let batch = indoc! {"
+            ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+            byr:1937 iyr:2017 cid:147 hgt:183cm
+
+            iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+            hcl:#cfa07d byr:1929
+
+            hcl:#ae17e1 iyr:2013
+            eyr:2024
+            ecl:brn pid:760753108 byr:1931
+            hgt:179cm
+
+            hcl:#cfa07d eyr:2025 pid:166559648
+            iyr:2011 ecl:brn hgt:59in
+        "};
+
+        assert_eq!(Day04::part1(batch)?, 2);
+        assert_eq!(Day04::part2(batch)?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn strict_invalid_passports_test() {
+        let source = indoc! {"
+            eyr:1972 cid:100
+            hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+            iyr:2019
+            hcl:#602927 eyr:1967 hgt:170cm
+            ecl:grn pid:012533040 byr:1946
+
+            hcl:dab227 iyr:2012
+            ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+            hgt:59cm ecl:zzz
+            eyr:2038 hcl:74454a iyr:2023
+            pid:3556412378 byr:2007
+        "};
+
+        let mut lex = Fact::lexer(source);
+        let passports = PassportParser::new(&mut lex).collect::<Vec<Passport>>();
+
+        assert_eq!(passports.len(), 4);
+        assert_eq!(passports.iter().filter(|p| p.is_valid_strict()).count(), 0);
+    }
+
+    #[test]
+    fn strict_valid_passports_test() {
+        let source = indoc! {"
+            pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+            hcl:#623a2f
+
+            eyr:2029 ecl:blu cid:129 byr:1989
+            iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+            hcl:#888785
+            hgt:164cm byr:2001 iyr:2015 cid:88
+            pid:545766238 ecl:hzl
+            eyr:2022
+
+            iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719
+        "};
+
+        let mut lex = Fact::lexer(source);
+        let passports = PassportParser::new(&mut lex).collect::<Vec<Passport>>();
+
+        assert_eq!(passports.len(), 4);
+        assert_eq!(passports.iter().filter(|p| p.is_valid_strict()).count(), 4);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn passport_to_json_test() {
+        let source = indoc! {"
+            ecl:gry pid:860033327 eyr:2020
+            hcl:#fffffd byr:1937 iyr:2017 hgt:183cm
+        "};
+
+        let mut lex = Fact::lexer(source);
+        let passport = PassportParser::new(&mut lex).next().unwrap();
+
+        let json = passport.to_json();
+        assert!(json.contains(r#""birth_year":"1937""#));
+        assert!(json.contains(r#""passport_id":"860033327""#));
+        assert!(json.contains(r#""country_id":null"#));
+
+        let owned = serde_json::from_str::<OwnedPassport>(&json).unwrap();
+        assert_eq!(owned, OwnedPassport::from(&passport));
+        assert_eq!(owned.eye_color.as_deref(), Some("gry"));
+        assert_eq!(owned.country_id, None);
+    }
+
+    #[test]
+    fn diagnostics_test() {
+        let source = indoc! {"
+            ecl:gry pid:860033327 eyr:2020 wat:yes
+            hcl:#fffffd byr:1937 iyr:2017 hgt:183cm
+        "};
+
+        let mut lex = Fact::lexer(source);
+        let mut parser = PassportParser::new(&mut lex);
+
+        let passport = parser.next().unwrap();
+        assert_eq!(passport.eye_color, Some("gry"));
+        assert_eq!(passport.birth_year, Some("1937"));
+
+        assert_eq!(
+            parser.diagnostics(),
+            [(
+                31..38,
+                "Expected a valid token, but found `wat:yes`".to_string()
+            )]
+        );
+        assert_eq!(parser.diagnostic_positions(), [(1, 32)]);
+    }
+
+    #[test]
+    fn validation_report_test() {
+        let source = indoc! {"
+            ecl:gry pid:860033327 eyr:2040
+            hcl:#fffffd byr:1937 iyr:2017 cid:147 hgt:190in
+        "};
+
+        let mut lex = Fact::lexer(source);
+        let passport = PassportParser::new(&mut lex).next().unwrap();
+
+        let report = passport.validate();
+
+        assert!(!report.is_valid());
+        assert_eq!(report.failures.len(), 2);
+        assert_eq!(
+            report.to_string(),
+            "eyr 2040 out of range 2020..=2030\nhgt 190in out of range 59..=76\n"
+        );
+    }
+
+    #[test]
+    fn validation_report_agrees_with_strict_test() {
+        let source = indoc! {"
+            pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+            hcl:#623a2f
+
+            hgt:59cm ecl:zzz
+            eyr:2038 hcl:74454a iyr:2023
+            pid:3556412378 byr:2007
+        "};
+
+        let mut lex = Fact::lexer(source);
+        let mut parser = PassportParser::new(&mut lex);
+
+        let valid = parser.next().unwrap();
+        assert!(valid.validate().is_valid());
+        assert!(valid.is_valid_strict());
+
+        let invalid = parser.next().unwrap();
+        assert_eq!(invalid.validate().failures.len(), 7);
+        assert!(!invalid.is_valid_strict());
+    }
+
+    #[test]
+    fn parse_height_test() {
+        assert_eq!(parse_height("183cm"), Some(Height::Cm(183)));
+        assert_eq!(parse_height("59in"), Some(Height::In(59)));
+        assert_eq!(parse_height("190"), None);
+        assert_eq!(parse_height("cm"), None);
+        assert_eq!(parse_height("+183cm"), None);
+        assert_eq!(parse_height("183cmx"), None);
+
+        assert!(parse_height("183cm").unwrap().is_valid());
+        assert!(parse_height("59in").unwrap().is_valid());
+        assert!(!parse_height("190in").unwrap().is_valid());
+        assert!(!parse_height("149cm").unwrap().is_valid());
+    }
+
+    #[test]
+    fn valid_hcl_test() {
+        assert!(valid_hcl("#123abc"));
+        assert!(!valid_hcl("#123abz"));
+        assert!(!valid_hcl("123abc"));
+        assert!(!valid_hcl("#123ABC"));
+        assert!(!valid_hcl("#123abcd"));
+        assert!(!valid_hcl("#zzz"));
+    }
+
+    #[test]
+    fn valid_pid_test() {
+        assert!(valid_pid("000000001"));
+        assert!(!valid_pid("0123456789"));
+        assert!(!valid_pid("01234567a"));
+        assert!(!valid_pid("#12345678"));
+    }
+
+    #[test]
+    fn eye_color_test() {
+        let colors = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]
+            .iter()
+            .map(|s| s.parse::<EyeColor>())
+            .collect::<Result<Vec<EyeColor>, ValidationError>>();
+
+        assert_eq!(
+            colors,
+            Ok(vec![
+                EyeColor::Amber,
+                EyeColor::Blue,
+                EyeColor::Brown,
+                EyeColor::Gray,
+                EyeColor::Green,
+                EyeColor::Hazel,
+                EyeColor::Other
+            ])
+        );
+
+        assert_eq!(
+            "wat".parse::<EyeColor>(),
+            Err(ValidationError::Unparseable {
+                field: "ecl",
+                value: "wat".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn parsed_passport_test() {
+        let source = indoc! {"
+            ecl:gry pid:860033327 eyr:2020
+            hcl:#fffffd byr:1937 iyr:2017 cid:147 hgt:183cm
+        "};
+
+        let mut lex = Fact::lexer(source);
+        let passport = PassportParser::new(&mut lex).next().unwrap();
+
+        assert_eq!(
+            passport.parsed(),
+            Ok(ParsedPassport {
+                birth_year: 1937,
+                country_id: Some("147"),
+                eye_color: EyeColor::Gray,
+                expiration_year: 2020,
+                hair_color: "#fffffd",
+                height: Height::Cm(183),
+                issue_year: 2017,
+                passport_id: "860033327",
+            })
+        );
+    }
+
+    #[test]
+    fn parsed_passport_errors_test() {
+        let source = indoc! {"
+            ecl:gry pid:860033327 eyr:20x0
+            hcl:#fffffd byr:1937 iyr:2017 cid:147 hgt:183cm
+
+            ecl:gry pid:860033327 eyr:2020
+            hcl:#fffffd iyr:2017 cid:147 hgt:183cm
+        "};
+
+        let mut lex = Fact::lexer(source);
+        let mut parser = PassportParser::new(&mut lex);
+
+        assert_eq!(
+            parser.next().unwrap().parsed(),
+            Err(ValidationError::Unparseable {
+                field: "eyr",
+                value: "20x0".to_string()
+            })
+        );
+        assert_eq!(
+            parser.next().unwrap().parsed(),
+            Err(ValidationError::Missing("byr"))
+        );
+    }
+
+    #[test]
+    fn multi_passport_parsing_test() {
+        let source = indoc! {"
+            ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+            byr:1937 iyr:2017 cid:147 hgt:183cm
+
+            iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+            hcl:#cfa07d byr:1929
+
+            hcl:#ae17e1 iyr:2013
+            eyr:2024
+            ecl:brn pid:760753108 byr:1931
+            hgt:179cm
+
+            hcl:#cfa07d eyr:2025 pid:166559648
+            iyr:2011 ecl:brn hgt:59in
+        "};
+
+        let mut lex = Fact::lexer(source);
+
+        let passports = PassportParser::new(&mut lex).collect::<Vec<Passport>>();
+
+        assert_eq!(passports.len(), 4);
+
+        let valid = passports.iter().filter(|f| f.is_valid()).count();
+        assert_eq!(valid, 2);
+    }
+
+    #[test]
+    fn partial_passport_parsing_test() {
+        let source = indoc! {"
+            ecl:gry pid:860033327 eyr:2020
+        "};
+
+        let mut lex = Fact::lexer(source);
+
+        let mut parser = PassportParser::new(&mut lex);
+        let passport = parser.next().unwrap();
+
+        assert_eq!(passport.birth_year, None);
+        assert_eq!(passport.country_id, None);
+        assert_eq!(passport.expiration_year, Some("2020"));
+        assert_eq!(passport.eye_color, Some("gry"));
+        assert_eq!(passport.hair_color, None);
+        assert_eq!(passport.height, None);
+        assert_eq!(passport.issue_year, None);
+        assert_eq!(passport.passport_id, Some("860033327"));
+
+        assert!(!passport.is_valid());
+    }
+
+    #[test]
+    fn passport_parsing_test() {
+        let source = indoc! {"
+            ecl:gry pid:860033327 eyr:2020
+            hcl:#fffffd byr:1937 iyr:2017 cid:147 hgt:183cm
+        "};
+
+        let mut lex = Fact::lexer(source);
+
+        let mut parser = PassportParser::new(&mut lex);
+        let passport = parser.next().unwrap();
+
+        assert_eq!(passport.birth_year, Some("1937"));
+        assert_eq!(passport.country_id, Some("147"));
+        assert_eq!(passport.expiration_year, Some("2020"));
+        assert_eq!(passport.eye_color, Some("gry"));
+        assert_eq!(passport.hair_color, Some("#fffffd"));
+        assert_eq!(passport.height, Some("183cm"));
+        assert_eq!(passport.issue_year, Some("2017"));
+        assert_eq!(passport.passport_id, Some("860033327"));
+
+        assert!(passport.is_valid());
+    }
+
+    #[test]
+    fn document_lexing_test() {
+        let source = indoc! {"
+            ecl:gry pid:860033327
+            eyr:2020
+            hcl:#fffffd byr:1937
+
+            iyr:2017 cid:147 hgt:183cm
+
+            eyr:2020
+        "};
+
+        let mut lex = Fact::lexer(source);
+
+        assert_eq!(lex.next(), Some(Fact::EyeColor("gry")));
+        assert_eq!(lex.next(), Some(Fact::PassportId("860033327")));
+        assert_eq!(lex.next(), Some(Fact::ExpirationYear("2020")));
+        assert_eq!(lex.next(), Some(Fact::HairColor("#fffffd")));
+        assert_eq!(lex.next(), Some(Fact::BirthYear("1937")));
+        assert_eq!(lex.next(), Some(Fact::DocumentEnd));
+
+        assert_eq!(lex.next(), Some(Fact::IssueYear("2017")));
+        assert_eq!(lex.next(), Some(Fact::CountryId("147")));
+        assert_eq!(lex.next(), Some(Fact::Height("183cm")));
+        assert_eq!(lex.next(), Some(Fact::DocumentEnd));
+
+        assert_eq!(lex.next(), Some(Fact::ExpirationYear("2020")));
+        assert_eq!(lex.next(), None);
+    }
+
+    #[test]
+    fn fact_lexing_test() {
+        let source = indoc! {"
+            ecl:gry pid:860033327 eyr:2020
+            hcl:#fffffd byr:1937 iyr:2017 cid:147 hgt:183cm
+        "};
+
+        let mut lex = Fact::lexer(source);
+
+        assert_eq!(lex.next(), Some(Fact::EyeColor("gry")));
+        assert_eq!(lex.next(), Some(Fact::PassportId("860033327")));
+        assert_eq!(lex.next(), Some(Fact::ExpirationYear("2020")));
+        assert_eq!(lex.next(), Some(Fact::HairColor("#fffffd")));
+        assert_eq!(lex.next(), Some(Fact::BirthYear("1937")));
+        assert_eq!(lex.next(), Some(Fact::IssueYear("2017")));
+        assert_eq!(lex.next(), Some(Fact::CountryId("147")));
+        assert_eq!(lex.next(), Some(Fact::Height("183cm")));
+        assert_eq!(lex.next(), None);
+    }
+}
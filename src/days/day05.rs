@@ -0,0 +1,278 @@
+use logos::{Lexer, Logos, Span};
+
+use crate::{line_col, missing_in_contiguous, AocError, Day, Solver};
+
+/// Day 5: Binary Boarding.
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Output = u32;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        seat_ids(input)
+            .into_iter()
+            .max()
+            .ok_or(AocError::NoSolution)
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        find_my_seat(&seat_ids(input)).ok_or(AocError::NoSolution)
+    }
+}
+
+impl Day for Day05 {
+    const DAY: u8 = 5;
+}
+
+fn seat_ids(input: &str) -> Vec<u32> {
+    let mut lexer = Partition::lexer(input);
+    PassParser::new(&mut lexer)
+        .map(|pass| pass.seat_id())
+        .collect()
+}
+
+/// Each letter of a boarding pass halves the remaining seats.
+#[derive(Logos, Debug, PartialEq, Clone, Copy)]
+pub enum Partition {
+    #[token("F")]
+    Front,
+
+    #[token("B")]
+    Back,
+
+    #[token("L")]
+    Left,
+
+    #[token("R")]
+    Right,
+
+    #[token("\n")]
+    PassEnd,
+
+    // Logos requires one token variant to handle errors,
+    // it can be named anything you wish.
+    #[error]
+    // We can also use this variant to define whitespace,
+    // or any other matches we wish to skip.
+    #[regex(r"[ \t\f]+", logos::skip)]
+    Error,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BoardingPass {
+    pub row: u32,
+    pub column: u32,
+}
+
+impl BoardingPass {
+    pub fn seat_id(&self) -> u32 {
+        self.row * 8 + self.column
+    }
+}
+
+pub struct PassParser<'a, 'source: 'a> {
+    tokens: &'a mut Lexer<'source, Partition>,
+    diagnostics: Vec<(Span, String)>,
+}
+
+impl<'a, 'source> PassParser<'a, 'source> {
+    pub fn new(tokens: &'a mut Lexer<'source, Partition>) -> Self {
+        Self {
+            tokens,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Everything the parser skipped so far: unrecognized letters, and passes
+    /// that don't spell out exactly 7 row letters followed by 3 column letters.
+    pub fn diagnostics(&self) -> &[(Span, String)] {
+        &self.diagnostics
+    }
+
+    /// The `(line, column)` of each diagnostic, both starting at 1.
+    pub fn diagnostic_positions(&self) -> Vec<(usize, usize)> {
+        self.diagnostics
+            .iter()
+            .map(|(span, _)| line_col(self.tokens.source(), span.start))
+            .collect()
+    }
+}
+
+impl<'a, 'source: 'a> Iterator for PassParser<'a, 'source> {
+    type Item = BoardingPass;
+
+    /// A pass is just a pair of binary numbers: `F`/`B` spell out the row
+    /// with `B` as a one, and `L`/`R` spell out the column with `R` as a one.
+    /// Malformed passes are recorded in `diagnostics` and skipped.
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut row = 0;
+        let mut column = 0;
+        let mut rows = 0;
+        let mut columns = 0;
+        let mut in_order = true;
+        let mut unrecognized = false;
+        let mut start = None;
+
+        loop {
+            let token = self.tokens.next();
+            let pass_start = match (token, start) {
+                (None, None) => return None,
+                // skip blank lines
+                (Some(Partition::PassEnd), None) => continue,
+                (_, Some(pass_start)) => pass_start,
+                (Some(_), None) => *start.insert(self.tokens.span().start),
+            };
+
+            match token {
+                None | Some(Partition::PassEnd) => {
+                    if in_order && !unrecognized && rows == 7 && columns == 3 {
+                        return Some(BoardingPass { row, column });
+                    }
+                    if !unrecognized {
+                        let pass_end = match token {
+                            Some(_) => self.tokens.span().start,
+                            None => self.tokens.source().len(),
+                        };
+                        let span = pass_start..pass_end;
+                        let message = format!(
+                            "Expected 7 of F or B followed by 3 of L or R, but found `{}`",
+                            self.tokens.source()[span.clone()].trim()
+                        );
+                        self.diagnostics.push((span, message));
+                    }
+
+                    row = 0;
+                    column = 0;
+                    rows = 0;
+                    columns = 0;
+                    in_order = true;
+                    unrecognized = false;
+                    start = None;
+                }
+                Some(Partition::Front) | Some(Partition::Back) => {
+                    in_order &= columns == 0;
+                    rows += 1;
+                    row = (row << 1) | (token == Some(Partition::Back)) as u32;
+                }
+                Some(Partition::Left) | Some(Partition::Right) => {
+                    columns += 1;
+                    column = (column << 1) | (token == Some(Partition::Right)) as u32;
+                }
+                Some(Partition::Error) => {
+                    unrecognized = true;
+                    self.diagnostics.push((
+                        self.tokens.span(),
+                        format!("Expected F, B, L or R, but found `{}`", self.tokens.slice()),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Our seat is the only one missing from the middle of the plane, so both of
+/// its neighbors are taken.
+pub fn find_my_seat(seat_ids: &[u32]) -> Option<u32> {
+    let mut sorted = seat_ids.to_vec();
+    sorted.sort_unstable();
+
+    missing_in_contiguous(&sorted)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        let passes = "BFFFBBFRRR\nFFFBBBFRRR\nBBFFBBFRLL\n";
+
+        assert_eq!(Day05::part1(passes)?, 820);
+        assert!(matches!(Day05::part2(passes), Err(AocError::NoSolution)));
+        Ok(())
+    }
+
+    #[test]
+    fn seat_id_test() {
+        let mut lex = Partition::lexer("FBFBBFFRLR");
+        let pass = PassParser::new(&mut lex).next().unwrap();
+
+        assert_eq!(pass, BoardingPass { row: 44, column: 5 });
+        assert_eq!(pass.seat_id(), 357);
+    }
+
+    #[test]
+    fn multiple_passes_test() {
+        let mut lex = Partition::lexer("BFFFBBFRRR\nFFFBBBFRRR\nBBFFBBFRLL\n");
+        let passes = PassParser::new(&mut lex).collect::<Vec<BoardingPass>>();
+
+        assert_eq!(
+            passes,
+            vec![
+                BoardingPass { row: 70, column: 7 },
+                BoardingPass { row: 14, column: 7 },
+                BoardingPass {
+                    row: 102,
+                    column: 4
+                },
+            ]
+        );
+
+        let ids = passes
+            .iter()
+            .map(BoardingPass::seat_id)
+            .collect::<Vec<u32>>();
+        assert_eq!(ids, vec![567, 119, 820]);
+    }
+
+    #[test]
+    fn malformed_passes_test() {
+        let mut lex = Partition::lexer("FBL\nFBFBBFFRLR\nFBFBBFFRLRL\nFBFXBBFFRLR\nFBFBBFRLRF\n");
+        let mut parser = PassParser::new(&mut lex);
+        let passes = parser.by_ref().collect::<Vec<BoardingPass>>();
+
+        assert_eq!(passes, vec![BoardingPass { row: 44, column: 5 }]);
+        assert_eq!(
+            parser.diagnostics(),
+            &[
+                (
+                    0..3,
+                    "Expected 7 of F or B followed by 3 of L or R, but found `FBL`".to_string()
+                ),
+                (
+                    15..26,
+                    "Expected 7 of F or B followed by 3 of L or R, but found `FBFBBFFRLRL`"
+                        .to_string()
+                ),
+                (30..31, "Expected F, B, L or R, but found `X`".to_string()),
+                (
+                    39..49,
+                    "Expected 7 of F or B followed by 3 of L or R, but found `FBFBBFRLRF`"
+                        .to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            parser.diagnostic_positions(),
+            vec![(1, 1), (3, 1), (4, 4), (5, 1)]
+        );
+    }
+
+    #[test]
+    fn find_my_seat_test() {
+        assert_eq!(find_my_seat(&[7, 3, 4, 6, 8]), Some(5));
+        assert_eq!(find_my_seat(&[3, 4, 5]), None);
+    }
+
+    #[test]
+    fn partition_lexing_test() {
+        let mut lex = Partition::lexer("FB\nLR");
+
+        assert_eq!(lex.next(), Some(Partition::Front));
+        assert_eq!(lex.next(), Some(Partition::Back));
+        assert_eq!(lex.next(), Some(Partition::PassEnd));
+        assert_eq!(lex.next(), Some(Partition::Left));
+        assert_eq!(lex.next(), Some(Partition::Right));
+        assert_eq!(lex.next(), None);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{paragraphs, AocError, Day, Solver};
+
+/// Day 6: Custom Customs.
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Output = u32;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        Ok(paragraphs(input).map(anyone_count).sum())
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        Ok(paragraphs(input).map(everyone_count).sum())
+    }
+}
+
+impl Day for Day06 {
+    const DAY: u8 = 6;
+}
+
+/// One bit per question, `a` through `z`.
+fn answers(person: &str) -> u32 {
+    person
+        .chars()
+        .filter(char::is_ascii_lowercase)
+        .fold(0, |mask, question| mask | 1 << (question as u8 - b'a'))
+}
+
+fn people(group: &str) -> Vec<u32> {
+    group.lines().map(answers).collect()
+}
+
+/// The questions answered by at least one of `masks`.
+fn group_union(masks: &[u32]) -> u32 {
+    masks.iter().fold(0, |all, mask| all | mask)
+}
+
+/// The questions answered by every one of `masks`. Nobody answered anything
+/// in an empty group, so that's no questions rather than all of them.
+fn group_intersection(masks: &[u32]) -> u32 {
+    match masks.split_first() {
+        Some((first, rest)) => rest.iter().fold(*first, |all, mask| all & mask),
+        None => 0,
+    }
+}
+
+/// Part 1: the questions anyone in the group answered "yes" to.
+pub fn anyone_count(group: &str) -> u32 {
+    group_union(&people(group)).count_ones()
+}
+
+/// Part 2: the questions everyone in the group answered "yes" to.
+pub fn everyone_count(group: &str) -> u32 {
+    group_intersection(&people(group)).count_ones()
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day06::part1(SAMPLE)?, 11);
+        assert_eq!(Day06::part2(SAMPLE)?, 6);
+        Ok(())
+    }
+
+    const SAMPLE: &str = indoc! {"
+        abc
+
+        a
+        b
+        c
+
+        ab
+        ac
+
+        a
+        a
+        a
+        a
+
+        b
+    "};
+
+    #[test]
+    fn answers_test() {
+        assert_eq!(answers("a"), 0b1);
+        assert_eq!(answers("abc"), 0b111);
+        assert_eq!(answers("z"), 1 << 25);
+        assert_eq!(answers(""), 0);
+    }
+
+    #[test]
+    fn group_masks_test() {
+        let masks = [answers("abc"), answers("ab"), answers("ac")];
+
+        assert_eq!(group_union(&masks), answers("abc"));
+        assert_eq!(group_union(&masks).count_ones(), 3);
+        assert_eq!(group_intersection(&masks), answers("a"));
+        assert_eq!(group_intersection(&masks).count_ones(), 1);
+        assert_eq!(group_union(&[]), 0);
+        assert_eq!(group_intersection(&[]), 0);
+    }
+
+    #[test]
+    fn anyone_count_test() {
+        let counts = paragraphs(SAMPLE).map(anyone_count).collect::<Vec<u32>>();
+
+        assert_eq!(counts, vec![3, 3, 3, 1, 1]);
+        assert_eq!(counts.iter().sum::<u32>(), 11);
+    }
+
+    #[test]
+    fn everyone_count_test() {
+        let counts = paragraphs(SAMPLE).map(everyone_count).collect::<Vec<u32>>();
+
+        assert_eq!(counts, vec![3, 0, 1, 1, 1]);
+        assert_eq!(counts.iter().sum::<u32>(), 6);
+    }
+}
@@ -0,0 +1,296 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use logos::{Lexer, Logos};
+
+use crate::{AocError, Day, Solver};
+
+/// Day 7: Handy Haversacks.
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Output = usize;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        Ok(containers_of(&bag_rules(input)?, "shiny gold"))
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        Ok(count_contained(&bag_rules(input)?, "shiny gold"))
+    }
+}
+
+impl Day for Day07 {
+    const DAY: u8 = 7;
+}
+
+fn bag_rules(input: &str) -> Result<BagRules, AocError> {
+    parse_rules(&mut RuleToken::lexer(input)).map_err(AocError::Parse)
+}
+
+#[derive(Logos, Debug, PartialEq, Clone, Copy)]
+pub enum RuleToken<'a> {
+    #[regex("[a-z]+")]
+    Word(&'a str),
+
+    #[token("bag")]
+    #[token("bags")]
+    Bag,
+
+    #[token("contain")]
+    Contain,
+
+    #[regex("[0-9]+", |lex| lex.slice().parse())]
+    Count(usize),
+
+    #[token(",")]
+    Comma,
+
+    #[token(".")]
+    RuleEnd,
+
+    // Logos requires one token variant to handle errors,
+    // it can be named anything you wish.
+    #[error]
+    // We can also use this variant to define whitespace,
+    // or any other matches we wish to skip.
+    #[regex(r"[ \t\n\f]+", logos::skip)]
+    Error,
+}
+
+/// Each bag color maps to the bags (and how many of each) it must contain.
+pub type BagRules = HashMap<String, Vec<(usize, String)>>;
+
+/// Colors are always two words, like `shiny gold`, followed by `bag(s)`.
+fn parse_bag<'source>(
+    first: Option<RuleToken<'source>>,
+    tokens: &mut Lexer<'source, RuleToken<'source>>,
+) -> Result<String, String> {
+    match (first, tokens.next(), tokens.next()) {
+        (Some(RuleToken::Word(shade)), Some(RuleToken::Word(hue)), Some(RuleToken::Bag)) => {
+            Ok(format!("{} {}", shade, hue))
+        }
+        _ => Err(format!("Expected a bag color at {:?}!", tokens.span())),
+    }
+}
+
+pub fn parse_rules<'source>(
+    tokens: &mut Lexer<'source, RuleToken<'source>>,
+) -> Result<BagRules, String> {
+    let mut rules = BagRules::new();
+
+    while let Some(token) = tokens.next() {
+        let outer = parse_bag(Some(token), tokens)?;
+
+        if let Some(RuleToken::Contain) = tokens.next() {
+            // good parse
+        } else {
+            return Err(format!("Expected `contain` at {:?}!", tokens.span()));
+        }
+
+        let mut contents = Vec::new();
+        loop {
+            match tokens.next() {
+                Some(RuleToken::Count(count)) => {
+                    let inner = parse_bag(tokens.next(), tokens)?;
+                    contents.push((count, inner));
+                }
+                Some(RuleToken::Word("no")) => {
+                    // the only other option is `no other bags`
+                    if parse_bag(Some(RuleToken::Word("no")), tokens)? != "no other" {
+                        return Err(format!("Expected `no other bags` at {:?}!", tokens.span()));
+                    }
+                }
+                _ => return Err(format!("Expected a count of bags at {:?}!", tokens.span())),
+            }
+
+            match tokens.next() {
+                Some(RuleToken::Comma) => continue,
+                Some(RuleToken::RuleEnd) => break,
+                _ => return Err(format!("Expected `,` or `.` at {:?}!", tokens.span())),
+            }
+        }
+
+        rules.insert(outer, contents);
+    }
+
+    Ok(rules)
+}
+
+/// Part 1: how many colors could eventually end up holding a `target` bag?
+pub fn containers_of(rules: &BagRules, target: &str) -> usize {
+    let mut held_by: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (outer, contents) in rules.iter() {
+        for (_, inner) in contents.iter() {
+            held_by.entry(inner).or_default().push(outer);
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(target);
+
+    while let Some(color) = queue.pop_front() {
+        for outer in held_by.get(color).into_iter().flatten() {
+            if seen.insert(*outer) {
+                queue.push_back(outer);
+            }
+        }
+    }
+
+    seen.len()
+}
+
+/// Part 2: how many bags does a `color` bag hold, all the way down?
+///
+/// Rather than recursing, this walks the rules with an explicit stack so deep
+/// nesting can't overflow, and caches each color's total so shared contents
+/// are only counted once. The puzzle's rules never loop, but if they did the
+/// loop would be cut short and undercounted instead of spinning forever.
+pub fn count_contained(rules: &BagRules, color: &str) -> usize {
+    let mut totals: HashMap<&str, usize> = HashMap::new();
+    let mut expanded = HashSet::new();
+    let mut stack = vec![color];
+
+    while let Some(&color) = stack.last() {
+        if totals.contains_key(color) {
+            stack.pop();
+            continue;
+        }
+
+        let contents = rules.get(color).into_iter().flatten();
+        if expanded.insert(color) {
+            let pending = contents
+                .filter(|(_, inner)| !totals.contains_key(inner.as_str()))
+                .map(|(_, inner)| inner.as_str())
+                .collect::<Vec<&str>>();
+            if !pending.is_empty() {
+                stack.extend(pending);
+                continue;
+            }
+        }
+
+        let total = rules
+            .get(color)
+            .into_iter()
+            .flatten()
+            .map(|(count, inner)| count * (1 + totals.get(inner.as_str()).unwrap_or(&0)))
+            .sum();
+        totals.insert(color, total);
+        stack.pop();
+    }
+
+    totals[color]
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day07::part1(SAMPLE)?, 4);
+        assert_eq!(Day07::part2(SAMPLE)?, 32);
+        Ok(())
+    }
+
+    const SAMPLE: &str = indoc! {"
+        light red bags contain 1 bright white bag, 2 muted yellow bags.
+        dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+        bright white bags contain 1 shiny gold bag.
+        muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+        shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+        dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+        vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+        faded blue bags contain no other bags.
+        dotted black bags contain no other bags.
+    "};
+
+    #[test]
+    fn parse_rules_test() -> Result<(), String> {
+        let rules = parse_rules(&mut RuleToken::lexer(SAMPLE))?;
+
+        assert_eq!(rules.len(), 9);
+        assert_eq!(
+            rules["light red"],
+            vec![
+                (1, "bright white".to_string()),
+                (2, "muted yellow".to_string())
+            ]
+        );
+        assert_eq!(rules["faded blue"], vec![]);
+        Ok(())
+    }
+
+    #[test]
+    fn containers_of_test() -> Result<(), String> {
+        let rules = parse_rules(&mut RuleToken::lexer(SAMPLE))?;
+
+        assert_eq!(containers_of(&rules, "shiny gold"), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn count_contained_test() -> Result<(), String> {
+        let rules = parse_rules(&mut RuleToken::lexer(SAMPLE))?;
+
+        assert_eq!(count_contained(&rules, "shiny gold"), 32);
+        assert_eq!(count_contained(&rules, "dark olive"), 7);
+        assert_eq!(count_contained(&rules, "faded blue"), 0);
+        assert_eq!(count_contained(&rules, "unknown color"), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn count_contained_deep_nesting() -> Result<(), String> {
+        // a chain this long would overflow the stack if it were walked
+        // recursively, and doubles at each level without the cache
+        let depth = 100_000;
+        let rules = (0..depth)
+            .map(|n| {
+                (
+                    format!("shade {}", n),
+                    vec![(1, format!("shade {}", n + 1))],
+                )
+            })
+            .collect::<BagRules>();
+
+        assert_eq!(count_contained(&rules, "shade 0"), depth);
+        Ok(())
+    }
+
+    #[test]
+    fn count_contained_looping_rules() -> Result<(), String> {
+        let rules = parse_rules(&mut RuleToken::lexer(indoc! {"
+            dark red bags contain 2 dark blue bags.
+            dark blue bags contain 3 dark red bags.
+        "}))?;
+
+        // the blue bags are still being counted when red comes back around, so
+        // they're treated as empty
+        assert_eq!(count_contained(&rules, "dark red"), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn rule_lexing_test() {
+        let mut lex =
+            RuleToken::lexer("bright white bags contain 1 shiny gold bag, no other bags.");
+
+        assert_eq!(lex.next(), Some(RuleToken::Word("bright")));
+        assert_eq!(lex.next(), Some(RuleToken::Word("white")));
+        assert_eq!(lex.next(), Some(RuleToken::Bag));
+        assert_eq!(lex.next(), Some(RuleToken::Contain));
+        assert_eq!(lex.next(), Some(RuleToken::Count(1)));
+        assert_eq!(lex.next(), Some(RuleToken::Word("shiny")));
+        assert_eq!(lex.next(), Some(RuleToken::Word("gold")));
+        assert_eq!(lex.next(), Some(RuleToken::Bag));
+        assert_eq!(lex.next(), Some(RuleToken::Comma));
+        assert_eq!(lex.next(), Some(RuleToken::Word("no")));
+        assert_eq!(lex.next(), Some(RuleToken::Word("other")));
+        assert_eq!(lex.next(), Some(RuleToken::Bag));
+        assert_eq!(lex.next(), Some(RuleToken::RuleEnd));
+        assert_eq!(lex.next(), None);
+    }
+}
@@ -0,0 +1,268 @@
+use std::collections::HashSet;
+
+use logos::{Lexer, Logos};
+
+use crate::{AocError, Day, Solver};
+
+/// Day 8: Handheld Halting.
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Output = i64;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        match Console::new(&boot_code(input)?).run_until_loop_or_end() {
+            Outcome::Looped(acc) => Ok(acc),
+            Outcome::Halted(_) => Err(AocError::NoSolution),
+        }
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        find_terminating_variant(&boot_code(input)?).ok_or(AocError::NoSolution)
+    }
+}
+
+impl Day for Day08 {
+    const DAY: u8 = 8;
+}
+
+fn boot_code(input: &str) -> Result<Vec<Instruction>, AocError> {
+    parse_program(&mut OpToken::lexer(input)).map_err(AocError::Parse)
+}
+
+#[derive(Logos, Debug, PartialEq, Clone, Copy)]
+pub enum OpToken {
+    #[token("acc")]
+    Acc,
+
+    #[token("jmp")]
+    Jmp,
+
+    #[token("nop")]
+    Nop,
+
+    #[regex("[+-][0-9]+", |lex| lex.slice().parse())]
+    Argument(i64),
+
+    // Logos requires one token variant to handle errors,
+    // it can be named anything you wish.
+    #[error]
+    // We can also use this variant to define whitespace,
+    // or any other matches we wish to skip.
+    #[regex(r"[ \t\n\f]+", logos::skip)]
+    Error,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Instruction {
+    Acc(i64),
+    Jmp(i64),
+    Nop(i64),
+}
+
+impl Instruction {
+    /// Swaps a `jmp` for a `nop` and vice versa, leaving `acc` alone.
+    fn flipped(self) -> Option<Self> {
+        match self {
+            Instruction::Acc(_) => None,
+            Instruction::Jmp(arg) => Some(Instruction::Nop(arg)),
+            Instruction::Nop(arg) => Some(Instruction::Jmp(arg)),
+        }
+    }
+}
+
+pub fn parse_program(tokens: &mut Lexer<OpToken>) -> Result<Vec<Instruction>, String> {
+    let mut program = Vec::new();
+
+    while let Some(op) = tokens.next() {
+        let arg = match tokens.next() {
+            Some(OpToken::Argument(arg)) => arg,
+            _ => {
+                return Err(format!(
+                    "Expected a signed argument at {:?}!",
+                    tokens.span()
+                ))
+            }
+        };
+
+        program.push(match op {
+            OpToken::Acc => Instruction::Acc(arg),
+            OpToken::Jmp => Instruction::Jmp(arg),
+            OpToken::Nop => Instruction::Nop(arg),
+            _ => return Err(format!("Expected an operation at {:?}!", tokens.span())),
+        });
+    }
+
+    Ok(program)
+}
+
+/// How a program run came to an end.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Outcome {
+    /// Ran off the end of the program, with the final accumulator.
+    Halted(i64),
+    /// About to run an instruction a second time, with the accumulator so far.
+    Looped(i64),
+}
+
+pub struct Console<'p> {
+    program: &'p [Instruction],
+    pc: usize,
+    acc: i64,
+}
+
+impl<'p> Console<'p> {
+    pub fn new(program: &'p [Instruction]) -> Self {
+        Self {
+            program,
+            pc: 0,
+            acc: 0,
+        }
+    }
+
+    /// Runs a single instruction, returning `true` once the program counter
+    /// has moved past the last instruction.
+    fn step(&mut self) -> bool {
+        match self.program.get(self.pc) {
+            None => return true,
+            Some(Instruction::Acc(arg)) => {
+                self.acc += arg;
+                self.pc += 1;
+            }
+            Some(Instruction::Jmp(arg)) => {
+                // jumping before the start can never halt normally, so clamp
+                // to the first instruction and let the loop check catch it
+                self.pc = (self.pc as i64 + arg).max(0) as usize;
+            }
+            Some(Instruction::Nop(_)) => self.pc += 1,
+        }
+        self.pc >= self.program.len()
+    }
+
+    /// Runs until the program either halts or is about to repeat itself.
+    pub fn run_until_loop_or_end(&mut self) -> Outcome {
+        let mut visited = HashSet::new();
+
+        while visited.insert(self.pc) {
+            if self.step() {
+                return Outcome::Halted(self.acc);
+            }
+        }
+        Outcome::Looped(self.acc)
+    }
+}
+
+/// Part 2: exactly one corrupted `jmp` or `nop` keeps the program from halting.
+/// Flips each of them in turn and returns the accumulator of the first variant
+/// that runs off the end.
+pub fn find_terminating_variant(program: &[Instruction]) -> Option<i64> {
+    let mut patched = program.to_vec();
+
+    for i in 0..program.len() {
+        let fix = match program[i].flipped() {
+            Some(fix) => fix,
+            None => continue,
+        };
+
+        patched[i] = fix;
+        if let Outcome::Halted(acc) = Console::new(&patched).run_until_loop_or_end() {
+            return Some(acc);
+        }
+        patched[i] = program[i];
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day08::part1(SAMPLE)?, 5);
+        assert_eq!(Day08::part2(SAMPLE)?, 8);
+        Ok(())
+    }
+
+    const SAMPLE: &str = indoc! {"
+        nop +0
+        acc +1
+        jmp +4
+        acc +3
+        jmp -3
+        acc -99
+        acc +1
+        jmp -4
+        acc +6
+    "};
+
+    #[test]
+    fn parse_program_test() -> Result<(), String> {
+        let program = parse_program(&mut OpToken::lexer(SAMPLE))?;
+
+        assert_eq!(program.len(), 9);
+        assert_eq!(program[0], Instruction::Nop(0));
+        assert_eq!(program[4], Instruction::Jmp(-3));
+        assert_eq!(program[5], Instruction::Acc(-99));
+        Ok(())
+    }
+
+    #[test]
+    fn step_test() -> Result<(), String> {
+        let program = parse_program(&mut OpToken::lexer("acc +2\nnop -1\n"))?;
+        let mut console = Console::new(&program);
+
+        assert!(!console.step());
+        assert_eq!(console.acc, 2);
+        assert!(console.step());
+        assert_eq!(console.pc, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn run_until_loop_test() -> Result<(), String> {
+        let program = parse_program(&mut OpToken::lexer(SAMPLE))?;
+
+        assert_eq!(
+            Console::new(&program).run_until_loop_or_end(),
+            Outcome::Looped(5)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn find_terminating_variant_test() -> Result<(), String> {
+        let program = parse_program(&mut OpToken::lexer(SAMPLE))?;
+
+        assert_eq!(find_terminating_variant(&program), Some(8));
+        Ok(())
+    }
+
+    #[test]
+    fn repaired_program_halts() -> Result<(), String> {
+        let mut program = parse_program(&mut OpToken::lexer(SAMPLE))?;
+        assert_eq!(
+            Console::new(&program).run_until_loop_or_end(),
+            Outcome::Looped(5)
+        );
+
+        // the second to last instruction is the corrupted `jmp -4`
+        program[7] = program[7].flipped().unwrap();
+        assert_eq!(
+            Console::new(&program).run_until_loop_or_end(),
+            Outcome::Halted(8)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unrepairable_program() -> Result<(), String> {
+        let program = parse_program(&mut OpToken::lexer("jmp +0\njmp -1\n"))?;
+
+        assert_eq!(find_terminating_variant(&program), None);
+        Ok(())
+    }
+}
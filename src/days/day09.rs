@@ -0,0 +1,169 @@
+use std::cmp::Ordering;
+
+use crate::{AocError, Day, Solver};
+
+/// Day 9: Encoding Error.
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Output = u64;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        first_invalid(&numbers(input)?, PREAMBLE).ok_or(AocError::NoSolution)
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        let numbers = numbers(input)?;
+        let invalid = first_invalid(&numbers, PREAMBLE).ok_or(AocError::NoSolution)?;
+        encryption_weakness(&numbers, invalid).ok_or(AocError::NoSolution)
+    }
+}
+
+impl Day for Day09 {
+    const DAY: u8 = 9;
+}
+
+/// How many numbers come before the first one to check, in the real input.
+pub const PREAMBLE: usize = 25;
+
+fn numbers(input: &str) -> Result<Vec<u64>, AocError> {
+    parse_numbers(input).map_err(|e| AocError::Parse(e.to_string()))
+}
+
+pub fn parse_numbers(input: &str) -> Result<Vec<u64>, std::num::ParseIntError> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+/// Part 1: every number after the `preamble` must be the sum of two different
+/// numbers among the `preamble` numbers right before it.
+pub fn first_invalid(numbers: &[u64], preamble: usize) -> Option<u64> {
+    numbers.windows(preamble + 1).find_map(|window| {
+        let (previous, target) = window.split_at(preamble);
+        let target = target[0];
+
+        let summable = previous
+            .iter()
+            .enumerate()
+            .any(|(i, a)| previous[i + 1..].iter().any(|b| a != b && a + b == target));
+
+        if summable {
+            None
+        } else {
+            Some(target)
+        }
+    })
+}
+
+/// Finds a contiguous run of at least two numbers adding up to `target`, by
+/// sliding a window's ends along the list. The run is `data[start..end]`, so
+/// `end` is one past its last number.
+fn contiguous_sum_range(data: &[u64], target: u64) -> Option<(usize, usize)> {
+    let mut start = 0;
+    let mut end = 0;
+    let mut sum = 0;
+
+    loop {
+        match sum.cmp(&target) {
+            Ordering::Equal if end - start >= 2 => return Some((start, end)),
+            Ordering::Less | Ordering::Equal => {
+                sum += data.get(end)?;
+                end += 1;
+            }
+            Ordering::Greater => {
+                sum -= data[start];
+                start += 1;
+            }
+        }
+    }
+}
+
+/// Part 2: the smallest plus the largest number of the contiguous run.
+pub fn encryption_weakness(numbers: &[u64], target: u64) -> Option<u64> {
+    let (start, end) = contiguous_sum_range(numbers, target)?;
+    let run = &numbers[start..end];
+
+    Some(run.iter().min()? + run.iter().max()?)
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        // 1 to 25 as the preamble, then 26 = 1 + 25, but nothing makes 100
+        let numbers = (1..=26)
+            .chain(std::iter::once(100))
+            .map(|n| n.to_string())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        assert_eq!(Day09::part1(&numbers)?, 100);
+        // 9 + 10 + ... + 16 = 100
+        assert_eq!(Day09::part2(&numbers)?, 25);
+        Ok(())
+    }
+
+    const SAMPLE: &str = indoc! {"
+        35
+        20
+        15
+        25
+        47
+        40
+        62
+        55
+        65
+        95
+        102
+        117
+        150
+        182
+        127
+        219
+        299
+        277
+        309
+        576
+    "};
+
+    #[test]
+    fn first_invalid_test() {
+        let numbers = parse_numbers(SAMPLE).unwrap();
+
+        assert_eq!(numbers.len(), 20);
+        assert_eq!(first_invalid(&numbers, 5), Some(127));
+    }
+
+    #[test]
+    fn all_valid_test() {
+        let numbers = (1..=25).chain(vec![26, 49]).collect::<Vec<u64>>();
+
+        assert_eq!(first_invalid(&numbers, 25), None);
+        assert_eq!(first_invalid(&[1, 2, 3, 100], 3), Some(100));
+    }
+
+    #[test]
+    fn encryption_weakness_test() {
+        let numbers = parse_numbers(SAMPLE).unwrap();
+
+        assert_eq!(contiguous_sum_range(&numbers, 127), Some((2, 6)));
+        assert_eq!(encryption_weakness(&numbers, 127), Some(62));
+    }
+
+    #[test]
+    fn contiguous_sum_range_test() {
+        assert_eq!(contiguous_sum_range(&[1, 2, 3, 4], 7), Some((2, 4)));
+        // a single number isn't a run, even when it's the target
+        assert_eq!(contiguous_sum_range(&[5, 1, 4], 5), Some((1, 3)));
+        assert_eq!(contiguous_sum_range(&[5, 9], 5), None);
+        assert_eq!(contiguous_sum_range(&[], 5), None);
+    }
+}
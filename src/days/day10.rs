@@ -0,0 +1,181 @@
+use crate::{AocError, Day, Solver};
+
+/// Day 10: Adapter Array.
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Output = u64;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        let (ones, threes) = jolt_differences(&adapter_chain(&adapters(input)?));
+        Ok(ones * threes)
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        Ok(count_arrangements(&adapters(input)?))
+    }
+}
+
+impl Day for Day10 {
+    const DAY: u8 = 10;
+}
+
+fn adapters(input: &str) -> Result<Vec<u64>, AocError> {
+    parse_adapters(input).map_err(|e| AocError::Parse(e.to_string()))
+}
+
+/// Parses the adapter ratings, sorted from lowest to highest.
+pub fn parse_adapters(input: &str) -> Result<Vec<u64>, std::num::ParseIntError> {
+    let mut adapters = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::parse)
+        .collect::<Result<Vec<u64>, _>>()?;
+
+    adapters.sort_unstable();
+    Ok(adapters)
+}
+
+/// The full chain of `sorted_adapters`, from the 0-jolt outlet up to the
+/// device's built-in adapter 3 jolts above the highest one.
+pub fn adapter_chain(sorted_adapters: &[u64]) -> Vec<u64> {
+    let device = sorted_adapters.last().copied().unwrap_or(0) + 3;
+
+    std::iter::once(0)
+        .chain(sorted_adapters.iter().copied())
+        .chain(std::iter::once(device))
+        .collect()
+}
+
+/// Part 1: counts the 1-jolt and 3-jolt gaps between neighboring adapters.
+pub fn jolt_differences(chain: &[u64]) -> (u64, u64) {
+    chain
+        .windows(2)
+        .fold((0, 0), |(ones, threes), pair| match pair[1] - pair[0] {
+            1 => (ones + 1, threes),
+            3 => (ones, threes + 1),
+            _ => (ones, threes),
+        })
+}
+
+/// Part 2: the number of ways to reach each adapter is the sum of the ways to
+/// reach every adapter within 3 jolts below it, starting from the outlet.
+///
+/// A long enough run of 1-jolt gaps would grow the count past `u64`, but the
+/// puzzle inputs are broken up by 3-jolt gaps that every arrangement has to
+/// cross, which keeps the answer in the trillions. Overflowing panics rather
+/// than wrapping around to a wrong answer.
+pub fn count_arrangements(sorted_adapters: &[u64]) -> u64 {
+    let chain = adapter_chain(sorted_adapters);
+    let mut ways = vec![0u64; chain.len()];
+    ways[0] = 1;
+
+    for i in 1..chain.len() {
+        ways[i] = (i.saturating_sub(3)..i)
+            .filter(|&j| chain[i] - chain[j] <= 3)
+            .try_fold(0u64, |total, j| total.checked_add(ways[j]))
+            .expect("Too many arrangements to count in a u64!");
+    }
+
+    ways[chain.len() - 1]
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day10::part1(SMALL_SAMPLE)?, 35);
+        assert_eq!(Day10::part2(SMALL_SAMPLE)?, 8);
+        assert_eq!(Day10::part1(LARGE_SAMPLE)?, 220);
+        assert_eq!(Day10::part2(LARGE_SAMPLE)?, 19208);
+        Ok(())
+    }
+
+    const SMALL_SAMPLE: &str = indoc! {"
+        16
+        10
+        15
+        5
+        1
+        11
+        7
+        19
+        6
+        12
+        4
+    "};
+
+    const LARGE_SAMPLE: &str = indoc! {"
+        28
+        33
+        18
+        42
+        31
+        14
+        46
+        20
+        48
+        47
+        24
+        23
+        49
+        45
+        19
+        38
+        39
+        11
+        1
+        32
+        25
+        35
+        8
+        17
+        7
+        9
+        4
+        2
+        34
+        10
+        3
+    "};
+
+    #[test]
+    fn adapter_chain_test() {
+        let chain = adapter_chain(&parse_adapters(SMALL_SAMPLE).unwrap());
+
+        assert_eq!(chain, vec![0, 1, 4, 5, 6, 7, 10, 11, 12, 15, 16, 19, 22]);
+        assert_eq!(adapter_chain(&[]), vec![0, 3]);
+    }
+
+    #[test]
+    fn jolt_differences_test() {
+        let small = adapter_chain(&parse_adapters(SMALL_SAMPLE).unwrap());
+        let large = adapter_chain(&parse_adapters(LARGE_SAMPLE).unwrap());
+
+        assert_eq!(jolt_differences(&small), (7, 5));
+        assert_eq!(jolt_differences(&large), (22, 10));
+    }
+
+    #[test]
+    fn count_arrangements_test() {
+        let small = parse_adapters(SMALL_SAMPLE).unwrap();
+        let large = parse_adapters(LARGE_SAMPLE).unwrap();
+
+        assert_eq!(count_arrangements(&small), 8);
+        assert_eq!(count_arrangements(&large), 19208);
+        assert_eq!(count_arrangements(&[]), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many arrangements")]
+    fn count_arrangements_overflow() {
+        let adapters = (1..=100).collect::<Vec<u64>>();
+
+        count_arrangements(&adapters);
+    }
+}
@@ -0,0 +1,206 @@
+use std::mem;
+
+use crate::grid::Grid;
+use crate::{AocError, Day, Solver};
+
+/// Day 11: Seating System.
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Output = usize;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        Ok(stabilize(&seats(input)?, adjacent_occupied, 4))
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        Ok(stabilize(&seats(input)?, visible_occupied, 5))
+    }
+}
+
+impl Day for Day11 {
+    const DAY: u8 = 11;
+}
+
+fn seats(input: &str) -> Result<Grid<Seat>, AocError> {
+    Grid::parse_with(input, Seat::from_char).map_err(|e| AocError::Parse(e.to_string()))
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Seat {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+impl Seat {
+    pub fn from_char(c: char) -> Self {
+        match c {
+            'L' => Seat::Empty,
+            '#' => Seat::Occupied,
+            _ => Seat::Floor,
+        }
+    }
+}
+
+/// Part 1: only the eight seats touching this one matter.
+pub fn adjacent_occupied(grid: &Grid<Seat>, x: usize, y: usize) -> usize {
+    grid.neighbors8(x, y)
+        .filter(|&spot| grid[spot] == Seat::Occupied)
+        .count()
+}
+
+/// Part 2: people look past the floor to the first seat in each direction.
+///
+/// Each of the eight neighbors gives one direction to look along. This takes
+/// the parsed `Grid<Seat>` rather than raw `char`s, so it shares its
+/// signature with `adjacent_occupied` and `stabilize` can take either.
+pub fn visible_occupied(grid: &Grid<Seat>, x: usize, y: usize) -> usize {
+    grid.neighbors8(x, y)
+        .filter(|&(nx, ny)| {
+            let dx = nx as isize - x as isize;
+            let dy = ny as isize - y as isize;
+            let mut spot = Some((nx, ny));
+            while let Some((sx, sy)) = spot {
+                match grid[(sx, sy)] {
+                    Seat::Floor => {}
+                    seat => return seat == Seat::Occupied,
+                }
+                spot = sx
+                    .checked_add_signed(dx)
+                    .zip(sy.checked_add_signed(dy))
+                    .filter(|&(next_x, next_y)| grid.get(next_x, next_y).is_some());
+            }
+            false
+        })
+        .count()
+}
+
+/// Runs the seating rules until nobody moves, then counts the occupied seats.
+///
+/// The next generation is written into a second grid that swaps places with
+/// the current one, so nothing is reallocated between generations.
+pub fn stabilize<F>(seats: &Grid<Seat>, occupied_near: F, tolerance: usize) -> usize
+where
+    F: Fn(&Grid<Seat>, usize, usize) -> usize,
+{
+    let mut current = seats.clone();
+    let mut next = seats.clone();
+
+    loop {
+        let mut changed = false;
+
+        for y in 0..current.height() {
+            for x in 0..current.width() {
+                let seat = current[(x, y)];
+                next[(x, y)] = match seat {
+                    Seat::Floor => Seat::Floor,
+                    Seat::Empty if occupied_near(&current, x, y) == 0 => Seat::Occupied,
+                    Seat::Occupied if occupied_near(&current, x, y) >= tolerance => Seat::Empty,
+                    seat => seat,
+                };
+                changed |= next[(x, y)] != seat;
+            }
+        }
+
+        mem::swap(&mut current, &mut next);
+        if !changed {
+            break;
+        }
+    }
+
+    current
+        .cells()
+        .iter()
+        .filter(|&&seat| seat == Seat::Occupied)
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+
+    use crate::grid::GridError;
+
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day11::part1(SAMPLE)?, 37);
+        assert_eq!(Day11::part2(SAMPLE)?, 26);
+        Ok(())
+    }
+
+    const SAMPLE: &str = indoc! {"
+        L.LL.LL.LL
+        LLLLLLL.LL
+        L.L.L..L..
+        LLLL.LL.LL
+        L.LL.LL.LL
+        L.LLLLL.LL
+        ..L.L.....
+        LLLLLLLLLL
+        L.LLLLLL.L
+        L.LLLLL.LL
+    "};
+
+    #[test]
+    fn adjacent_stabilize_test() -> Result<(), GridError> {
+        let seats = Grid::parse_with(SAMPLE, Seat::from_char)?;
+
+        assert_eq!(stabilize(&seats, adjacent_occupied, 4), 37);
+        Ok(())
+    }
+
+    #[test]
+    fn visible_stabilize_test() -> Result<(), GridError> {
+        let seats = Grid::parse_with(SAMPLE, Seat::from_char)?;
+
+        assert_eq!(stabilize(&seats, visible_occupied, 5), 26);
+        Ok(())
+    }
+
+    #[test]
+    fn eight_visible_occupied_test() -> Result<(), GridError> {
+        let seats = Grid::parse_with(
+            indoc! {"
+                .......#.
+                ...#.....
+                .#.......
+                .........
+                ..#L....#
+                ....#....
+                .........
+                #........
+                ...#.....
+            "},
+            Seat::from_char,
+        )?;
+
+        assert_eq!(seats[(3, 4)], Seat::Empty);
+        assert_eq!(visible_occupied(&seats, 3, 4), 8);
+        assert_eq!(adjacent_occupied(&seats, 3, 4), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn visible_occupied_test() -> Result<(), GridError> {
+        let seats = Grid::parse_with(
+            indoc! {"
+                .##.##.
+                #.#.#.#
+                ##...##
+                ...L...
+                ##...##
+                #.#.#.#
+                .##.##.
+            "},
+            Seat::from_char,
+        )?;
+
+        assert_eq!(visible_occupied(&seats, 3, 3), 0);
+        assert_eq!(adjacent_occupied(&seats, 3, 3), 0);
+        assert_eq!(visible_occupied(&seats, 0, 1), 4);
+        Ok(())
+    }
+}
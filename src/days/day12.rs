@@ -0,0 +1,258 @@
+use logos::{Lexer, Logos};
+
+use crate::direction::Direction;
+use crate::point::Point;
+use crate::{AocError, Day, Solver};
+
+/// Day 12: Rain Risk.
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Output = i64;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        Ok(navigate(&actions(input)?, Mode::Heading))
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        Ok(navigate(&actions(input)?, Mode::Waypoint))
+    }
+}
+
+impl Day for Day12 {
+    const DAY: u8 = 12;
+}
+
+fn actions(input: &str) -> Result<Vec<Action>, AocError> {
+    parse_actions(&mut Action::lexer(input)).map_err(AocError::Parse)
+}
+
+/// Drops the action letter and parses the magnitude that follows it.
+fn magnitude(lex: &mut Lexer<Action>) -> Option<i32> {
+    lex.slice()[1..].parse().ok()
+}
+
+#[derive(Logos, Debug, PartialEq, Clone, Copy)]
+pub enum Action {
+    #[regex("N[0-9]+", magnitude)]
+    North(i32),
+
+    #[regex("S[0-9]+", magnitude)]
+    South(i32),
+
+    #[regex("E[0-9]+", magnitude)]
+    East(i32),
+
+    #[regex("W[0-9]+", magnitude)]
+    West(i32),
+
+    #[regex("L[0-9]+", magnitude)]
+    Left(i32),
+
+    #[regex("R[0-9]+", magnitude)]
+    Right(i32),
+
+    #[regex("F[0-9]+", magnitude)]
+    Forward(i32),
+
+    // Logos requires one token variant to handle errors,
+    // it can be named anything you wish.
+    #[error]
+    // We can also use this variant to define whitespace,
+    // or any other matches we wish to skip.
+    #[regex(r"[ \t\n\f]+", logos::skip)]
+    Error,
+}
+
+impl Action {
+    /// The compass heading and distance, if this is a compass action.
+    fn compass(self) -> Option<(Direction, i64)> {
+        match self {
+            Action::North(n) => Some((Direction::North, n.into())),
+            Action::South(n) => Some((Direction::South, n.into())),
+            Action::East(n) => Some((Direction::East, n.into())),
+            Action::West(n) => Some((Direction::West, n.into())),
+            _ => None,
+        }
+    }
+}
+
+/// What the compass actions move, which is the difference between the parts.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Mode {
+    /// Part 1: compass actions move the ship, and `F` follows its heading.
+    Heading,
+    /// Part 2: compass actions move a waypoint relative to the ship, and `F`
+    /// moves the ship towards the waypoint.
+    Waypoint,
+}
+
+/// The ship, along with both things `F` can steer it by. Only the one its
+/// `mode` uses changes.
+struct Ship {
+    mode: Mode,
+    position: Point,
+    /// Which way `F` goes in `Mode::Heading`.
+    heading: Direction,
+    /// Where `F` goes in `Mode::Waypoint`, relative to the ship.
+    waypoint: Point,
+}
+
+impl Ship {
+    fn new(mode: Mode) -> Self {
+        Self {
+            mode,
+            position: Point::ORIGIN,
+            heading: Direction::East,
+            waypoint: Point::new(10, 1),
+        }
+    }
+
+    /// Panics on a turn that isn't a multiple of 90 degrees.
+    fn apply(&mut self, action: Action) {
+        if let Some((direction, n)) = action.compass() {
+            let step = direction.delta() * n;
+            match self.mode {
+                Mode::Heading => self.position = self.position + step,
+                Mode::Waypoint => self.waypoint = self.waypoint + step,
+            }
+            return;
+        }
+
+        match action {
+            Action::Left(degrees) => self.turn(-degrees),
+            Action::Right(degrees) => self.turn(degrees),
+            Action::Forward(n) => {
+                let step = match self.mode {
+                    Mode::Heading => self.heading.delta(),
+                    Mode::Waypoint => self.waypoint,
+                };
+                self.position = self.position + step * n.into();
+            }
+            _ => {}
+        }
+    }
+
+    /// Turns clockwise, or counter-clockwise when `degrees` is negative.
+    fn turn(&mut self, degrees: i32) {
+        match self.mode {
+            Mode::Heading => self.heading = self.heading.turn(degrees),
+            Mode::Waypoint => self.waypoint = self.waypoint.rotate(degrees),
+        }
+    }
+}
+
+/// How far the ship ends up from where it started after every action.
+pub fn navigate(actions: &[Action], mode: Mode) -> i64 {
+    let mut ship = Ship::new(mode);
+    for &action in actions {
+        ship.apply(action);
+    }
+
+    ship.position.manhattan()
+}
+
+pub fn parse_actions(tokens: &mut Lexer<Action>) -> Result<Vec<Action>, String> {
+    let mut actions = Vec::new();
+
+    while let Some(action) = tokens.next() {
+        if action == Action::Error {
+            return Err(format!(
+                "Unknown action `{}` at {:?}!",
+                tokens.slice(),
+                tokens.span()
+            ));
+        }
+        actions.push(action);
+    }
+
+    Ok(actions)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day12::part1(SAMPLE)?, 25);
+        assert_eq!(Day12::part2(SAMPLE)?, 286);
+        Ok(())
+    }
+
+    const SAMPLE: &str = "F10\nN3\nF7\nR90\nF11\n";
+
+    #[test]
+    fn action_lexing_test() {
+        let mut lex = Action::lexer(SAMPLE);
+
+        assert_eq!(lex.next(), Some(Action::Forward(10)));
+        assert_eq!(lex.next(), Some(Action::North(3)));
+        assert_eq!(lex.next(), Some(Action::Forward(7)));
+        assert_eq!(lex.next(), Some(Action::Right(90)));
+        assert_eq!(lex.next(), Some(Action::Forward(11)));
+        assert_eq!(lex.next(), None);
+    }
+
+    #[test]
+    fn navigate_test() {
+        let actions = parse_actions(&mut Action::lexer(SAMPLE)).unwrap();
+
+        assert_eq!(navigate(&actions, Mode::Heading), 25);
+    }
+
+    #[test]
+    fn navigate_by_waypoint_test() {
+        let actions = parse_actions(&mut Action::lexer(SAMPLE)).unwrap();
+
+        assert_eq!(navigate(&actions, Mode::Waypoint), 286);
+    }
+
+    #[test]
+    fn unknown_action_test() {
+        assert_eq!(
+            parse_actions(&mut Action::lexer("F10\nQ3\n")),
+            Err("Unknown action `Q` at 4..5!".to_string())
+        );
+    }
+
+    #[test]
+    fn waypoint_ship_test() {
+        let mut ship = Ship::new(Mode::Waypoint);
+
+        let at = |ship: &Ship| {
+            (
+                (ship.position.x, ship.position.y),
+                (ship.waypoint.x, ship.waypoint.y),
+            )
+        };
+
+        ship.apply(Action::Forward(10));
+        assert_eq!(at(&ship), ((100, 10), (10, 1)));
+        ship.apply(Action::North(3));
+        assert_eq!(at(&ship), ((100, 10), (10, 4)));
+        ship.apply(Action::Forward(7));
+        assert_eq!(at(&ship), ((170, 38), (10, 4)));
+        ship.apply(Action::Right(90));
+        assert_eq!(at(&ship), ((170, 38), (4, -10)));
+        ship.apply(Action::Forward(11));
+        assert_eq!(at(&ship), ((214, -72), (4, -10)));
+    }
+
+    #[test]
+    fn heading_ship_test() {
+        let mut ship = Ship::new(Mode::Heading);
+
+        ship.apply(Action::Left(270));
+        assert_eq!(ship.heading, Direction::South);
+        ship.apply(Action::Forward(4));
+        ship.apply(Action::East(2));
+        assert_eq!(ship.position, Point::new(2, -4));
+    }
+
+    #[test]
+    #[should_panic(expected = "multiples of 90")]
+    fn odd_turn_test() {
+        Ship::new(Mode::Waypoint).apply(Action::Left(45));
+    }
+}
@@ -0,0 +1,175 @@
+use crate::math::crt;
+use crate::{AocError, Day, Solver};
+
+/// Day 13: Shuttle Search.
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Output = u64;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        earliest_bus(&notes(input)?).ok_or(AocError::NoSolution)
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        earliest_sequence(&notes(input)?.buses).ok_or(AocError::NoSolution)
+    }
+}
+
+impl Day for Day13 {
+    const DAY: u8 = 13;
+}
+
+fn notes(input: &str) -> Result<Notes, AocError> {
+    parse_notes(input).map_err(AocError::Parse)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Notes {
+    pub earliest: u64,
+    /// Bus IDs in schedule order, with `None` for the `x` slots we don't care about.
+    pub buses: Vec<Option<u64>>,
+}
+
+pub fn parse_notes(input: &str) -> Result<Notes, String> {
+    let mut lines = input.lines().map(str::trim).filter(|line| !line.is_empty());
+
+    let earliest = lines
+        .next()
+        .ok_or("Missing the earliest timestamp!")?
+        .parse()
+        .map_err(|e| format!("Bad timestamp: {}", e))?;
+
+    let buses = parse_schedule(lines.next().ok_or("Missing the bus schedule!")?)?;
+
+    Ok(Notes { earliest, buses })
+}
+
+/// Bus IDs are how many minutes each bus takes to loop around, so none of
+/// them can be 0.
+pub fn parse_schedule(schedule: &str) -> Result<Vec<Option<u64>>, String> {
+    schedule
+        .split(',')
+        .map(|slot| match slot.trim() {
+            "x" => Ok(None),
+            id => id
+                .parse::<u64>()
+                .map_err(|e| format!("Bad bus ID `{}`: {}", id, e))
+                .and_then(|id| match id {
+                    0 => Err("Bus IDs are loop times, so can't be 0!".to_string()),
+                    id => Ok(Some(id)),
+                }),
+        })
+        .collect()
+}
+
+/// Part 1: the bus ID times how many minutes we wait for the first bus that
+/// leaves at or after `earliest`.
+pub fn earliest_bus(notes: &Notes) -> Option<u64> {
+    notes
+        .buses
+        .iter()
+        .flatten()
+        .map(|&id| (id, (id - notes.earliest % id) % id))
+        .min_by_key(|&(_, wait)| wait)
+        .map(|(id, wait)| id * wait)
+}
+
+/// Part 2: the earliest `t` where the bus in slot `i` leaves at `t + i`.
+///
+/// Each bus gives a congruence `t ≡ -i (mod id)`, which the Chinese Remainder
+/// Theorem solves together. Returns `None` if two buses can never line up.
+pub fn earliest_sequence(buses: &[Option<u64>]) -> Option<u64> {
+    let congruences = buses
+        .iter()
+        .enumerate()
+        .filter_map(|(offset, id)| Some((-(offset as i128), (*id)? as i128)))
+        .collect::<Vec<(i128, i128)>>();
+
+    crt(&congruences).map(|time| time as u64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day13::part1(SAMPLE)?, 295);
+        assert_eq!(Day13::part2(SAMPLE)?, 1068781);
+        Ok(())
+    }
+
+    const SAMPLE: &str = "939\n7,13,x,x,59,x,31,19\n";
+
+    #[test]
+    fn parse_notes_test() -> Result<(), String> {
+        let notes = parse_notes(SAMPLE)?;
+
+        assert_eq!(notes.earliest, 939);
+        assert_eq!(
+            notes.buses,
+            vec![
+                Some(7),
+                Some(13),
+                None,
+                None,
+                Some(59),
+                None,
+                Some(31),
+                Some(19)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn earliest_bus_test() -> Result<(), String> {
+        let notes = parse_notes(SAMPLE)?;
+
+        assert_eq!(earliest_bus(&notes), Some(295));
+        Ok(())
+    }
+
+    #[test]
+    fn earliest_sequence_test() -> Result<(), String> {
+        let notes = parse_notes(SAMPLE)?;
+        assert_eq!(earliest_sequence(&notes.buses), Some(1068781));
+
+        let cases = [
+            ("17,x,13,19", 3417),
+            ("67,7,59,61", 754018),
+            ("67,x,7,59,61", 779210),
+            ("67,7,x,59,61", 1261476),
+            ("1789,37,47,1889", 1202161486),
+        ];
+        for &(schedule, expected) in cases.iter() {
+            assert_eq!(
+                earliest_sequence(&parse_schedule(schedule)?),
+                Some(expected),
+                "{}",
+                schedule
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn zero_bus_id_test() {
+        let error = Err("Bus IDs are loop times, so can't be 0!".to_string());
+
+        assert_eq!(parse_schedule("7,0,x"), error);
+        assert_eq!(parse_schedule("x,00"), error);
+        assert!(matches!(
+            Day13::part1("939\n7,0\n"),
+            Err(AocError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn impossible_sequence_test() -> Result<(), String> {
+        // a bus every 4 minutes can't leave one minute after a bus every 2
+        assert_eq!(earliest_sequence(&parse_schedule("2,4")?), None);
+        Ok(())
+    }
+}
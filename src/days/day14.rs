@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use logos::{Lexer, Logos};
+
+use crate::{AocError, Day, Solver};
+
+/// Day 14: Docking Data.
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Output = u64;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        Ok(run_v1(&program(input)?))
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        Ok(run_v2(&program(input)?))
+    }
+}
+
+impl Day for Day14 {
+    const DAY: u8 = 14;
+}
+
+fn program(input: &str) -> Result<Vec<Instruction<'_>>, AocError> {
+    parse_program(&mut Line::lexer(input)).map_err(AocError::Parse)
+}
+
+#[derive(Logos, Debug, PartialEq, Clone, Copy)]
+pub enum Line<'a> {
+    #[regex("mask = [01X]+", |lex| &lex.slice()["mask = ".len()..])]
+    Mask(&'a str),
+
+    #[regex(r"mem\[[0-9]+\]", |lex| {
+        let slice = lex.slice();
+        slice["mem[".len()..slice.len() - 1].parse()
+    })]
+    Mem(u64),
+
+    #[token("=")]
+    Assign,
+
+    #[regex("[0-9]+", |lex| lex.slice().parse())]
+    Value(u64),
+
+    // Logos requires one token variant to handle errors,
+    // it can be named anything you wish.
+    #[error]
+    // We can also use this variant to define whitespace,
+    // or any other matches we wish to skip.
+    #[regex(r"[ \t\n\f]+", logos::skip)]
+    Error,
+}
+
+/// A bitmask split into the bits it forces on, forces off, and leaves floating.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+struct Mask {
+    ones: u64,
+    zeros: u64,
+    floating: u64,
+}
+
+impl Mask {
+    fn parse(bits: &str) -> Self {
+        bits.chars().fold(Mask::default(), |mask, bit| {
+            let mask = Mask {
+                ones: mask.ones << 1,
+                zeros: mask.zeros << 1,
+                floating: mask.floating << 1,
+            };
+            match bit {
+                '1' => Mask {
+                    ones: mask.ones | 1,
+                    ..mask
+                },
+                '0' => Mask {
+                    zeros: mask.zeros | 1,
+                    ..mask
+                },
+                _ => Mask {
+                    floating: mask.floating | 1,
+                    ..mask
+                },
+            }
+        })
+    }
+
+    /// Version 1: `0`s and `1`s overwrite the value, `X`s leave it alone.
+    fn apply(&self, value: u64) -> u64 {
+        (value | self.ones) & !self.zeros
+    }
+
+    /// Version 2: `1`s overwrite the address, `0`s leave it alone, and each
+    /// `X` could be either, so this is every address those choices lead to.
+    fn addresses(&self, address: u64) -> Vec<u64> {
+        let base = (address | self.ones) & !self.floating;
+
+        // walk every subset of the floating bits, counting down from all of them
+        let mut addresses = Vec::with_capacity(1 << self.floating.count_ones());
+        let mut subset = self.floating;
+        loop {
+            addresses.push(base | subset);
+            if subset == 0 {
+                break;
+            }
+            subset = (subset - 1) & self.floating;
+        }
+        addresses
+    }
+}
+
+/// Every address a version 2 `mask` written as in the puzzle, like
+/// `000000000000000000000000000000X1001X`, sends a write to `addr` to. There is
+/// one for each way the `X` bits can be chosen, in ascending order.
+pub fn floating_addresses(mask: &str, addr: u64) -> Vec<u64> {
+    let mut addresses = Mask::parse(mask).addresses(addr);
+    addresses.sort_unstable();
+    addresses
+}
+
+/// Masks are kept as written, since each version of the decoder reads them
+/// differently.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Instruction<'a> {
+    SetMask(&'a str),
+    Write { address: u64, value: u64 },
+}
+
+pub fn parse_program<'source>(
+    tokens: &mut Lexer<'source, Line<'source>>,
+) -> Result<Vec<Instruction<'source>>, String> {
+    let mut program = Vec::new();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            Line::Mask(bits) => program.push(Instruction::SetMask(bits)),
+            Line::Mem(address) => match (tokens.next(), tokens.next()) {
+                (Some(Line::Assign), Some(Line::Value(value))) => {
+                    program.push(Instruction::Write { address, value })
+                }
+                _ => return Err(format!("Expected `= value` at {:?}!", tokens.span())),
+            },
+            _ => {
+                return Err(format!(
+                    "Expected `mask` or `mem` but found `{}` at {:?}!",
+                    tokens.slice(),
+                    tokens.span()
+                ))
+            }
+        }
+    }
+
+    Ok(program)
+}
+
+/// Part 1: the mask changes the values written to memory.
+pub fn run_v1(program: &[Instruction]) -> u64 {
+    let mut memory: HashMap<u64, u64> = HashMap::new();
+    let mut mask = Mask::default();
+
+    for instruction in program {
+        match *instruction {
+            Instruction::SetMask(bits) => mask = Mask::parse(bits),
+            Instruction::Write { address, value } => {
+                memory.insert(address, mask.apply(value));
+            }
+        }
+    }
+
+    memory.values().sum()
+}
+
+/// Part 2: the mask changes the addresses written to instead.
+pub fn run_v2(program: &[Instruction]) -> u64 {
+    let mut memory: HashMap<u64, u64> = HashMap::new();
+    let mut mask = "";
+
+    for instruction in program {
+        match *instruction {
+            Instruction::SetMask(bits) => mask = bits,
+            Instruction::Write { address, value } => {
+                for address in floating_addresses(mask, address) {
+                    memory.insert(address, value);
+                }
+            }
+        }
+    }
+
+    memory.values().sum()
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day14::part1(VALUE_SAMPLE)?, 165);
+        assert_eq!(Day14::part2(ADDRESS_SAMPLE)?, 208);
+        Ok(())
+    }
+
+    const VALUE_SAMPLE: &str = indoc! {"
+        mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+        mem[8] = 11
+        mem[7] = 101
+        mem[8] = 0
+    "};
+
+    const ADDRESS_SAMPLE: &str = indoc! {"
+        mask = 000000000000000000000000000000X1001X
+        mem[42] = 100
+        mask = 00000000000000000000000000000000X0XX
+        mem[26] = 1
+    "};
+
+    #[test]
+    fn line_lexing_test() {
+        let mut lex = Line::lexer("mask = X10\nmem[8] = 11\n");
+
+        assert_eq!(lex.next(), Some(Line::Mask("X10")));
+        assert_eq!(lex.next(), Some(Line::Mem(8)));
+        assert_eq!(lex.next(), Some(Line::Assign));
+        assert_eq!(lex.next(), Some(Line::Value(11)));
+        assert_eq!(lex.next(), None);
+    }
+
+    #[test]
+    fn mask_test() {
+        let mask = Mask::parse("X1XXXX0X");
+
+        assert_eq!(mask.apply(11), 73);
+        assert_eq!(mask.apply(101), 101);
+        assert_eq!(mask.apply(0), 64);
+
+        let mut addresses = Mask::parse("X1001X").addresses(42);
+        addresses.sort_unstable();
+        assert_eq!(addresses, vec![26, 27, 58, 59]);
+    }
+
+    #[test]
+    fn floating_addresses_test() {
+        assert_eq!(
+            floating_addresses("000000000000000000000000000000X1001X", 42),
+            vec![26, 27, 58, 59]
+        );
+        assert_eq!(
+            floating_addresses("00000000000000000000000000000000X0XX", 26),
+            vec![16, 17, 18, 19, 24, 25, 26, 27]
+        );
+        assert_eq!(floating_addresses("0101", 8), vec![13]);
+    }
+
+    #[test]
+    fn run_v1_test() -> Result<(), String> {
+        let program = parse_program(&mut Line::lexer(VALUE_SAMPLE))?;
+
+        assert_eq!(run_v1(&program), 165);
+        Ok(())
+    }
+
+    #[test]
+    fn run_v2_test() -> Result<(), String> {
+        let program = parse_program(&mut Line::lexer(ADDRESS_SAMPLE))?;
+
+        assert_eq!(run_v2(&program), 208);
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{AocError, Day, Solver};
+
+/// Day 15: Rambunctious Recitation.
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Output = u32;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        Ok(play(&starting(input)?, 2020))
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        Ok(play(&starting(input)?, 30_000_000))
+    }
+}
+
+impl Day for Day15 {
+    const DAY: u8 = 15;
+}
+
+fn starting(input: &str) -> Result<Vec<u32>, AocError> {
+    parse_starting(input).map_err(|e| AocError::Parse(e.to_string()))
+}
+
+pub fn parse_starting(input: &str) -> Result<Vec<u32>, std::num::ParseIntError> {
+    input.trim().split(',').map(|n| n.trim().parse()).collect()
+}
+
+/// Plays the memory game until the `turn`th number is spoken.
+///
+/// Every number spoken after the starting list is a gap between two turns, so
+/// the turn each number was last spoken on fits in a flat `Vec` indexed by the
+/// number itself. Zero marks a number that hasn't been spoken yet, since turns
+/// count from one.
+pub fn play(starting: &[u32], turn: u32) -> u32 {
+    if (turn as usize) <= starting.len() {
+        return turn.checked_sub(1).map_or(0, |i| starting[i as usize]);
+    }
+
+    let largest = starting.iter().max().map_or(0, |&n| n + 1);
+    let mut last_spoken = vec![0u32; turn.max(largest) as usize];
+
+    let (&last, earlier) = match starting.split_last() {
+        Some(split) => split,
+        None => return 0,
+    };
+    for (i, &n) in earlier.iter().enumerate() {
+        last_spoken[n as usize] = i as u32 + 1;
+    }
+
+    let mut current = last;
+    for now in starting.len() as u32..turn {
+        let seen = last_spoken[current as usize];
+        last_spoken[current as usize] = now;
+        current = if seen == 0 { 0 } else { now - seen };
+    }
+
+    current
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day15::part1("0,3,6\n")?, 436);
+        assert!(Day15::part1("0,x,6").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn play_turns_test() {
+        let starting = parse_starting("0,3,6\n").unwrap();
+        let spoken = (1..=10)
+            .map(|turn| play(&starting, turn))
+            .collect::<Vec<u32>>();
+
+        assert_eq!(spoken, vec![0, 3, 6, 0, 3, 3, 1, 0, 4, 0]);
+    }
+
+    #[test]
+    fn play_2020_test() {
+        let cases = [
+            ("0,3,6", 436),
+            ("1,3,2", 1),
+            ("2,1,3", 10),
+            ("1,2,3", 27),
+            ("2,3,1", 78),
+            ("3,2,1", 438),
+            ("3,1,2", 1836),
+        ];
+
+        for &(starting, expected) in cases.iter() {
+            assert_eq!(
+                play(&parse_starting(starting).unwrap(), 2020),
+                expected,
+                "{}",
+                starting
+            );
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+
+use crate::interval::IntervalSet;
+use crate::{paragraphs, AocError, Day, Solver};
+
+/// Day 16: Ticket Translation.
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Output = u64;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        Ok(parse_notes(input).map_err(AocError::Parse)?.error_rate())
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        parse_notes(input)
+            .map_err(AocError::Parse)?
+            .departure_product()
+            .ok_or(AocError::NoSolution)
+    }
+}
+
+impl Day for Day16 {
+    const DAY: u8 = 16;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Field<'s> {
+    name: &'s str,
+    valid: IntervalSet,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Notes<'s> {
+    fields: Vec<Field<'s>>,
+    mine: Vec<u64>,
+    nearby: Vec<Vec<u64>>,
+}
+
+fn parse_range(range: &str) -> Result<std::ops::RangeInclusive<u64>, String> {
+    let (low, high) = range
+        .trim()
+        .split_once('-')
+        .ok_or(format!("Expected a range like `1-3`, found `{}`!", range))?;
+    let bound = |n: &str| {
+        n.parse::<u64>()
+            .map_err(|e| format!("Bad bound `{}`: {}", n, e))
+    };
+
+    Ok(bound(low)?..=bound(high)?)
+}
+
+/// Parses a rule like `class: 1-3 or 5-7`.
+fn parse_field(line: &str) -> Result<Field<'_>, String> {
+    let (name, ranges) = line
+        .split_once(": ")
+        .ok_or(format!("Expected a field rule, found `{}`!", line))?;
+
+    Ok(Field {
+        name,
+        valid: ranges
+            .split(" or ")
+            .map(parse_range)
+            .collect::<Result<IntervalSet, String>>()?,
+    })
+}
+
+fn parse_ticket(line: &str) -> Result<Vec<u64>, String> {
+    line.split(',')
+        .map(|n| {
+            n.trim()
+                .parse()
+                .map_err(|e| format!("Bad ticket value `{}`: {}", n, e))
+        })
+        .collect()
+}
+
+/// Tickets follow their section's header line, like `your ticket:`.
+fn parse_tickets(section: &str, header: &str) -> Result<Vec<Vec<u64>>, String> {
+    let mut lines = section.lines();
+    if lines.next() != Some(header) {
+        return Err(format!("Expected the `{}` section!", header));
+    }
+    lines.map(parse_ticket).collect()
+}
+
+pub fn parse_notes(input: &str) -> Result<Notes<'_>, String> {
+    let mut sections = paragraphs(input);
+    let mut next_section = || sections.next().ok_or("Expected three sections of notes!");
+
+    let fields = next_section()?
+        .lines()
+        .map(parse_field)
+        .collect::<Result<Vec<Field>, String>>()?;
+    let mine = parse_tickets(next_section()?, "your ticket:")?
+        .pop()
+        .ok_or("Missing your ticket!")?;
+    let nearby = parse_tickets(next_section()?, "nearby tickets:")?;
+
+    Ok(Notes {
+        fields,
+        mine,
+        nearby,
+    })
+}
+
+impl<'s> Notes<'s> {
+    fn any_field_allows(&self, value: u64) -> bool {
+        self.fields.iter().any(|field| field.valid.contains(value))
+    }
+
+    /// Part 1: sums every nearby ticket value that no field could hold.
+    pub fn error_rate(&self) -> u64 {
+        self.nearby
+            .iter()
+            .flatten()
+            .filter(|&&value| !self.any_field_allows(value))
+            .sum()
+    }
+
+    /// The nearby tickets where every value fits at least one field.
+    fn valid_tickets(&self) -> Vec<Vec<u64>> {
+        self.nearby
+            .iter()
+            .filter(|ticket| ticket.iter().all(|&value| self.any_field_allows(value)))
+            .cloned()
+            .collect()
+    }
+
+    /// Part 2: multiplies the values on our ticket for every `departure` field.
+    pub fn departure_product(&self) -> Option<u64> {
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| (field.name.to_string(), field.valid.clone()))
+            .collect::<Vec<(String, IntervalSet)>>();
+        let columns = assign_fields(&fields, &self.valid_tickets());
+        if columns.len() != fields.len() {
+            return None;
+        }
+
+        Some(
+            columns
+                .iter()
+                .filter(|(name, _)| name.starts_with("departure"))
+                .map(|(_, &column)| self.mine[column])
+                .product(),
+        )
+    }
+}
+
+/// Works out which column of `valid_tickets` each of the `fields` lives in.
+///
+/// Some field always fits exactly one remaining column, so that pairing is
+/// fixed and removed from the other fields' options until none are left. If
+/// the tickets don't pin every field down, only the ones they do are returned.
+fn assign_fields(
+    fields: &[(String, IntervalSet)],
+    valid_tickets: &[Vec<u64>],
+) -> HashMap<String, usize> {
+    let columns = valid_tickets.first().map_or(0, Vec::len);
+    let mut options = fields
+        .iter()
+        .map(|(_, valid)| {
+            (0..columns)
+                .filter(|&column| {
+                    valid_tickets
+                        .iter()
+                        .all(|ticket| ticket.get(column).is_some_and(|&v| valid.contains(v)))
+                })
+                .collect::<Vec<usize>>()
+        })
+        .collect::<Vec<Vec<usize>>>();
+
+    let mut assigned = HashMap::new();
+    while let Some((field, column)) = options
+        .iter()
+        .enumerate()
+        .find(|(field, choices)| !assigned.contains_key(&fields[*field].0) && choices.len() == 1)
+        .map(|(field, choices)| (field, choices[0]))
+    {
+        assigned.insert(fields[field].0.clone(), column);
+        for choices in options.iter_mut() {
+            choices.retain(|&choice| choice != column);
+        }
+    }
+
+    assigned
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        let input = ORDERING_SAMPLE.replace("row", "departure row");
+
+        assert_eq!(Day16::part1(SAMPLE)?, 71);
+        assert_eq!(Day16::part2(&input)?, 11);
+        Ok(())
+    }
+
+    const SAMPLE: &str = indoc! {"
+        class: 1-3 or 5-7
+        row: 6-11 or 33-44
+        seat: 13-40 or 45-50
+
+        your ticket:
+        7,1,14
+
+        nearby tickets:
+        7,3,47
+        40,4,50
+        55,2,20
+        38,6,12
+    "};
+
+    const ORDERING_SAMPLE: &str = indoc! {"
+        class: 0-1 or 4-19
+        row: 0-5 or 8-19
+        seat: 0-13 or 16-19
+
+        your ticket:
+        11,12,13
+
+        nearby tickets:
+        3,9,18
+        15,1,5
+        5,14,9
+    "};
+
+    #[test]
+    fn parse_notes_test() -> Result<(), String> {
+        let notes = parse_notes(SAMPLE)?;
+
+        assert_eq!(notes.fields.len(), 3);
+        assert_eq!(notes.fields[1].name, "row");
+        assert!(notes.fields[1].valid.contains(33));
+        assert!(!notes.fields[1].valid.contains(12));
+        assert_eq!(notes.mine, vec![7, 1, 14]);
+        assert_eq!(notes.nearby.len(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn error_rate_test() -> Result<(), String> {
+        let notes = parse_notes(SAMPLE)?;
+
+        assert_eq!(notes.error_rate(), 71);
+        Ok(())
+    }
+
+    #[test]
+    fn assign_fields_test() -> Result<(), String> {
+        let notes = parse_notes(ORDERING_SAMPLE)?;
+        let fields = notes
+            .fields
+            .iter()
+            .map(|field| (field.name.to_string(), field.valid.clone()))
+            .collect::<Vec<(String, IntervalSet)>>();
+        let columns = assign_fields(&fields, &notes.valid_tickets());
+
+        assert_eq!(columns.len(), 3);
+        assert_eq!(columns["row"], 0);
+        assert_eq!(columns["class"], 1);
+        assert_eq!(columns["seat"], 2);
+        assert_eq!(assign_fields(&[], &[]), HashMap::new());
+        Ok(())
+    }
+
+    #[test]
+    fn departure_product_test() -> Result<(), String> {
+        let input = ORDERING_SAMPLE.replace("row", "departure row");
+        let notes = parse_notes(&input)?;
+
+        assert_eq!(notes.departure_product(), Some(11));
+        Ok(())
+    }
+}
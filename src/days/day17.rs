@@ -0,0 +1,86 @@
+use crate::grid::SparseGrid;
+use crate::{AocError, Day, Solver};
+
+/// Day 17: Conway Cubes.
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Output = usize;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        Ok(boot::<3>(input, 6))
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        Ok(boot::<4>(input, 6))
+    }
+}
+
+impl Day for Day17 {
+    const DAY: u8 = 17;
+}
+
+/// Reads the `x`/`y` slice of active cubes, with every other axis at zero.
+fn parse_slice<const N: usize>(input: &str) -> SparseGrid<[i32; N]> {
+    let mut pocket = SparseGrid::new();
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.trim().chars().enumerate() {
+            if c == '#' {
+                let mut cube = [0; N];
+                cube[0] = x as i32;
+                cube[1] = y as i32;
+                pocket.activate(cube);
+            }
+        }
+    }
+
+    pocket
+}
+
+/// Counts the active cubes left after the boot process's `cycles`. Each cycle,
+/// active cubes stay active with 2 or 3 active neighbors, and inactive cubes
+/// become active with exactly 3.
+pub fn boot<const N: usize>(input: &str, cycles: usize) -> usize {
+    (0..cycles)
+        .fold(parse_slice::<N>(input), |pocket, _| {
+            pocket.step(|active, n| n == 3 || (active && n == 2))
+        })
+        .len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day17::part1(SAMPLE)?, 112);
+        assert_eq!(Day17::part2(SAMPLE)?, 848);
+        Ok(())
+    }
+
+    const SAMPLE: &str = ".#.\n..#\n###\n";
+
+    #[test]
+    fn parse_slice_test() {
+        let pocket = parse_slice::<3>(SAMPLE);
+
+        assert_eq!(pocket.len(), 5);
+        assert!(pocket.is_active(&[1, 0, 0]));
+        assert!(pocket.is_active(&[2, 2, 0]));
+        assert!(!pocket.is_active(&[0, 0, 0]));
+    }
+
+    #[test]
+    fn boot_3d_test() {
+        assert_eq!(boot::<3>(SAMPLE, 1), 11);
+        assert_eq!(boot::<3>(SAMPLE, 6), 112);
+    }
+
+    #[test]
+    fn boot_4d_test() {
+        assert_eq!(boot::<4>(SAMPLE, 1), 29);
+        assert_eq!(boot::<4>(SAMPLE, 6), 848);
+    }
+}
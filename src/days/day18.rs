@@ -0,0 +1,220 @@
+use logos::Logos;
+
+use crate::{AocError, Day, Solver};
+
+/// Day 18: Operation Order.
+pub struct Day18;
+
+impl Solver for Day18 {
+    type Output = u64;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        homework_sum(input, Precedence::Flat).map_err(AocError::Parse)
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        homework_sum(input, Precedence::AddFirst).map_err(AocError::Parse)
+    }
+}
+
+impl Day for Day18 {
+    const DAY: u8 = 18;
+}
+
+#[derive(Logos, Debug, PartialEq, Clone, Copy)]
+enum Token {
+    #[regex("[0-9]+", |lex| lex.slice().parse())]
+    Number(u64),
+
+    #[token("+")]
+    Plus,
+
+    #[token("*")]
+    Times,
+
+    #[token("(")]
+    Open,
+
+    #[token(")")]
+    Close,
+
+    #[token("\n")]
+    LineEnd,
+
+    // Logos requires one token variant to handle errors,
+    // it can be named anything you wish.
+    #[error]
+    // We can also use this variant to define whitespace,
+    // or any other matches we wish to skip.
+    #[regex(r"[ \t\f]+", logos::skip)]
+    Error,
+}
+
+/// How tightly each operator binds, which is all that differs between parts.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Precedence {
+    /// Part 1: `+` and `*` bind equally, so everything runs left to right.
+    Flat,
+    /// Part 2: `+` binds tighter than `*`.
+    AddFirst,
+}
+
+impl Precedence {
+    /// Binding strength of an operator, where higher binds tighter.
+    fn of(self, op: Token) -> u8 {
+        match (self, op) {
+            (Precedence::AddFirst, Token::Plus) => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// A precedence-climbing evaluator over a single line's tokens.
+struct Evaluator<'t> {
+    tokens: &'t [Token],
+    position: usize,
+    precedence: Precedence,
+}
+
+impl<'t> Evaluator<'t> {
+    fn new(tokens: &'t [Token], precedence: Precedence) -> Self {
+        Self {
+            tokens,
+            position: 0,
+            precedence,
+        }
+    }
+
+    fn next_token(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).copied();
+        self.position += 1;
+        token
+    }
+
+    /// Evaluates the whole line, which must be one complete expression.
+    fn evaluate(&mut self) -> Result<u64, String> {
+        let value = self.expression(0)?;
+
+        match self.next_token() {
+            None => Ok(value),
+            Some(token) => Err(format!("Unexpected {:?} after the expression!", token)),
+        }
+    }
+
+    /// Evaluates operators binding at least as tightly as `min_precedence`.
+    fn expression(&mut self, min_precedence: u8) -> Result<u64, String> {
+        let mut value = self.operand()?;
+
+        while let Some(&op) = self.tokens.get(self.position) {
+            let precedence = match op {
+                Token::Plus | Token::Times => self.precedence.of(op),
+                _ => break,
+            };
+            if precedence < min_precedence {
+                break;
+            }
+            self.position += 1;
+
+            // operators are left associative, so the right side only takes
+            // the operators that bind strictly tighter than this one
+            let rhs = self.expression(precedence + 1)?;
+            value = match op {
+                Token::Plus => value + rhs,
+                _ => value * rhs,
+            };
+        }
+
+        Ok(value)
+    }
+
+    /// A number or a parenthesized expression.
+    fn operand(&mut self) -> Result<u64, String> {
+        match self.next_token() {
+            Some(Token::Number(n)) => Ok(n),
+            Some(Token::Open) => {
+                let value = self.expression(0)?;
+                match self.next_token() {
+                    Some(Token::Close) => Ok(value),
+                    other => Err(format!("Expected `)` but found {:?}!", other)),
+                }
+            }
+            other => Err(format!("Expected a number or `(` but found {:?}!", other)),
+        }
+    }
+}
+
+/// Evaluates a single expression.
+fn eval(expr: &str, mode: Precedence) -> Result<u64, String> {
+    let tokens = Token::lexer(expr).collect::<Vec<Token>>();
+
+    Evaluator::new(&tokens, mode).evaluate()
+}
+
+/// Evaluates every line of homework and sums the results.
+pub fn homework_sum(input: &str, precedence: Precedence) -> Result<u64, String> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| eval(line, precedence))
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        let homework = "1 + 2 * 3 + 4 * 5 + 6\n2 * 3 + (4 * 5)\n";
+
+        assert_eq!(Day18::part1(homework)?, 71 + 26);
+        assert_eq!(Day18::part2(homework)?, 231 + 46);
+        assert!(Day18::part1("1 + (2").is_err());
+        Ok(())
+    }
+
+    const CASES: [(&str, u64, u64); 6] = [
+        ("1 + 2 * 3 + 4 * 5 + 6", 71, 231),
+        ("1 + (2 * 3) + (4 * (5 + 6))", 51, 51),
+        ("2 * 3 + (4 * 5)", 26, 46),
+        ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 437, 1445),
+        ("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 12240, 669060),
+        (
+            "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2",
+            13632,
+            23340,
+        ),
+    ];
+
+    #[test]
+    fn flat_test() -> Result<(), String> {
+        for &(line, expected, _) in CASES.iter() {
+            assert_eq!(eval(line, Precedence::Flat)?, expected, "{}", line);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn add_first_test() -> Result<(), String> {
+        for &(line, _, expected) in CASES.iter() {
+            assert_eq!(eval(line, Precedence::AddFirst)?, expected, "{}", line);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn homework_sum_test() -> Result<(), String> {
+        let homework = "2 * 3 + (4 * 5)\n5 + (8 * 3 + 9 + 3 * 4 * 3)\n";
+
+        assert_eq!(homework_sum(homework, Precedence::Flat)?, 26 + 437);
+        Ok(())
+    }
+
+    #[test]
+    fn malformed_expression_test() {
+        assert!(eval("(1 + 2", Precedence::Flat).is_err());
+        assert!(eval("1 + * 2", Precedence::AddFirst).is_err());
+        assert!(eval("1 2", Precedence::Flat).is_err());
+        assert!(eval("", Precedence::Flat).is_err());
+    }
+}
@@ -0,0 +1,312 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{paragraphs, AocError, Day, Solver};
+
+/// Day 19: Monster Messages.
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Output = usize;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        let (rules, messages) = parse_input(input).map_err(AocError::Parse)?;
+        Ok(count_matches(&rules, &messages))
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        let (rules, messages) = parse_input(input).map_err(AocError::Parse)?;
+        Ok(count_matches(&with_loops(&rules), &messages))
+    }
+}
+
+impl Day for Day19 {
+    const DAY: u8 = 19;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    /// Matches exactly one character.
+    Literal(char),
+    /// Matches any one of the sequences, where each is a list of rule numbers
+    /// that must match one after another.
+    Alternatives(Vec<Vec<usize>>),
+}
+
+pub type Rules = HashMap<usize, Rule>;
+
+/// Parses a rule like `1: 2 3 | 3 2` or `4: "a"`.
+fn parse_rule(line: &str) -> Result<(usize, Rule), String> {
+    let (number, body) = line
+        .split_once(": ")
+        .ok_or(format!("Expected a numbered rule, found `{}`!", line))?;
+    let number = number
+        .parse()
+        .map_err(|e| format!("Bad rule number `{}`: {}", number, e))?;
+
+    let body = body.trim();
+    if let Some(literal) = body.strip_prefix('"').and_then(|b| b.strip_suffix('"')) {
+        let mut chars = literal.chars();
+        return match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok((number, Rule::Literal(c))),
+            _ => Err(format!("Expected a single character in `{}`!", line)),
+        };
+    }
+
+    let alternatives = body
+        .split('|')
+        .map(|sequence| {
+            sequence
+                .split_whitespace()
+                .map(|n| {
+                    n.parse()
+                        .map_err(|e| format!("Bad rule reference `{}`: {}", n, e))
+                })
+                .collect::<Result<Vec<usize>, String>>()
+        })
+        .collect::<Result<Vec<Vec<usize>>, String>>()?;
+
+    Ok((number, Rule::Alternatives(alternatives)))
+}
+
+pub fn parse_input(input: &str) -> Result<(Rules, Vec<&str>), String> {
+    let mut sections = paragraphs(input);
+
+    let rules = sections
+        .next()
+        .ok_or("Missing the rules!")?
+        .lines()
+        .map(parse_rule)
+        .collect::<Result<Rules, String>>()?;
+    let messages = sections.next().map_or(vec![], |m| m.lines().collect());
+
+    Ok((rules, messages))
+}
+
+/// Every way `rule` can match the start of `message`, as the number of
+/// characters each way consumes.
+///
+/// Returning all of them, rather than just the first, is what lets a
+/// sequence backtrack into a shorter match of an earlier rule, which the
+/// looping rules in part 2 depend on. Those loops consume at least one
+/// character before recursing, so they always run out of message eventually.
+fn match_lengths(rules: &Rules, rule: usize, message: &str) -> Vec<usize> {
+    match_lengths_from(rules, rule, message, &mut HashSet::new())
+}
+
+/// `match_lengths`, remembering which rules are already being tried against
+/// which remaining `message`. A rule that comes back around to itself
+/// without consuming anything, like `8: 8 42`, is cut off there so it can't
+/// recurse forever.
+///
+/// That only guarantees termination: left recursion isn't supported, and a
+/// left-recursive rule misses every match that would go through the loop.
+/// The puzzle's looping rules are all right-recursive, so this never comes up.
+fn match_lengths_from(
+    rules: &Rules,
+    rule: usize,
+    message: &str,
+    active: &mut HashSet<(usize, usize)>,
+) -> Vec<usize> {
+    let alternatives = match rules.get(&rule) {
+        None => return vec![],
+        Some(Rule::Literal(c)) => {
+            return if message.starts_with(*c) {
+                vec![c.len_utf8()]
+            } else {
+                vec![]
+            }
+        }
+        Some(Rule::Alternatives(alternatives)) => alternatives,
+    };
+
+    // the remaining length pins down where in the full message this is
+    let key = (rule, message.len());
+    if !active.insert(key) {
+        return vec![];
+    }
+
+    let mut lengths = Vec::new();
+    for sequence in alternatives {
+        let mut consumed = vec![0];
+        for &next in sequence {
+            let mut extended = Vec::new();
+            for start in consumed {
+                for length in match_lengths_from(rules, next, &message[start..], active) {
+                    extended.push(start + length);
+                }
+            }
+            consumed = extended;
+            if consumed.is_empty() {
+                break;
+            }
+        }
+        lengths.extend(consumed);
+    }
+
+    active.remove(&key);
+    lengths.sort_unstable();
+    lengths.dedup();
+    lengths
+}
+
+/// A message only counts when rule 0 matches all of it.
+fn matches_fully(rules: &Rules, message: &str) -> bool {
+    match_lengths(rules, 0, message).contains(&message.len())
+}
+
+pub fn count_matches(rules: &Rules, messages: &[&str]) -> usize {
+    messages
+        .iter()
+        .filter(|message| matches_fully(rules, message))
+        .count()
+}
+
+/// Part 2: rules 8 and 11 are swapped out for versions that refer to themselves.
+pub fn with_loops(rules: &Rules) -> Rules {
+    let mut looping = rules.clone();
+    looping.insert(8, Rule::Alternatives(vec![vec![42], vec![42, 8]]));
+    looping.insert(11, Rule::Alternatives(vec![vec![42, 31], vec![42, 11, 31]]));
+    looping
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day19::part1(SAMPLE)?, 2);
+        assert_eq!(Day19::part1(LOOPING_SAMPLE)?, 3);
+        assert_eq!(Day19::part2(LOOPING_SAMPLE)?, 12);
+        Ok(())
+    }
+
+    const SAMPLE: &str = indoc! {r#"
+        0: 4 1 5
+        1: 2 3 | 3 2
+        2: 4 4 | 5 5
+        3: 4 5 | 5 4
+        4: "a"
+        5: "b"
+
+        ababbb
+        bababa
+        abbbab
+        aaabbb
+        aaaabbb
+    "#};
+
+    const LOOPING_SAMPLE: &str = indoc! {r#"
+        42: 9 14 | 10 1
+        9: 14 27 | 1 26
+        10: 23 14 | 28 1
+        1: "a"
+        11: 42 31
+        5: 1 14 | 15 1
+        19: 14 1 | 14 14
+        12: 24 14 | 19 1
+        16: 15 1 | 14 14
+        31: 14 17 | 1 13
+        6: 14 14 | 1 14
+        2: 1 24 | 14 4
+        0: 8 11
+        13: 14 3 | 1 12
+        15: 1 | 14
+        17: 14 2 | 1 7
+        23: 25 1 | 22 14
+        28: 16 1
+        4: 1 1
+        20: 14 14 | 1 15
+        3: 5 14 | 16 1
+        27: 1 6 | 14 18
+        14: "b"
+        21: 14 1 | 1 14
+        25: 1 1 | 1 14
+        22: 14 14
+        8: 42
+        26: 14 22 | 1 20
+        18: 15 15
+        7: 14 5 | 1 21
+        24: 14 1
+
+        abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+        bbabbbbaabaabba
+        babbbbaabbbbbabbbbbbaabaaabaaa
+        aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+        bbbbbbbaaaabbbbaaabbabaaa
+        bbbababbbbaaaaaaaabbababaaababaabab
+        ababaaaaaabaaab
+        ababaaaaabbbaba
+        baabbaaaabbaaaababbaababb
+        abbbbabbbbaaaababbbbbbaaaababb
+        aaaaabbaabaaaaababaa
+        aaaabbaaaabbaaa
+        aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+        babaaabbbaaabaababbaabababaaab
+        aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba
+    "#};
+
+    #[test]
+    fn parse_rule_test() -> Result<(), String> {
+        assert_eq!(parse_rule(r#"4: "a""#)?, (4, Rule::Literal('a')));
+        assert_eq!(
+            parse_rule("1: 2 3 | 3 2")?,
+            (1, Rule::Alternatives(vec![vec![2, 3], vec![3, 2]]))
+        );
+        assert!(parse_rule("x: 1").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn count_matches_test() -> Result<(), String> {
+        let (rules, messages) = parse_input(SAMPLE)?;
+
+        assert_eq!(messages.len(), 5);
+        assert_eq!(count_matches(&rules, &messages), 2);
+        assert!(matches_fully(&rules, "ababbb"));
+        assert!(!matches_fully(&rules, "aaaabbb"));
+        Ok(())
+    }
+
+    #[test]
+    fn match_lengths_test() -> Result<(), String> {
+        let (rules, _) = parse_input(LOOPING_SAMPLE)?;
+        let rules = with_loops(&rules);
+
+        // rule 8 is one or more 42s, which each match five characters here,
+        // but the last five only match the 31 that rule 11 needs
+        let message = "bbabbbbaabaabba";
+        assert_eq!(match_lengths(&rules, 42, message), vec![5]);
+        assert_eq!(match_lengths(&rules, 8, message), vec![5, 10]);
+        assert_eq!(match_lengths(&rules, 31, &message[10..]), vec![5]);
+        assert!(matches_fully(&rules, message));
+        Ok(())
+    }
+
+    #[test]
+    fn left_recursive_rules_terminate() -> Result<(), String> {
+        let (rules, _) = parse_input(indoc! {r#"
+            0: 0 1 | 1
+            1: "a"
+        "#})?;
+
+        // left recursion isn't supported: matching finishes, but the loop is
+        // cut off before it consumes anything, so only the non-looping
+        // alternative matches. A grammar would also match 2 and 3 here.
+        assert_eq!(match_lengths(&rules, 0, "aaa"), vec![1]);
+        assert!(matches_fully(&rules, "a"));
+        assert!(!matches_fully(&rules, "aa"));
+        Ok(())
+    }
+
+    #[test]
+    fn looping_rules_test() -> Result<(), String> {
+        let (rules, messages) = parse_input(LOOPING_SAMPLE)?;
+
+        assert_eq!(count_matches(&rules, &messages), 3);
+        assert_eq!(count_matches(&with_loops(&rules), &messages), 12);
+        Ok(())
+    }
+}
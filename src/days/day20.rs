@@ -0,0 +1,506 @@
+use std::collections::HashMap;
+
+use crate::grid::Grid;
+use crate::{paragraphs, Answer, AocError, Day, Solver};
+
+/// Day 20: Jurassic Jigsaw.
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Output = Answer;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        Ok(corner_product(&parse_tiles(input).map_err(AocError::Parse)?).into())
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        let tiles = parse_tiles(input).map_err(AocError::Parse)?;
+        let image = assemble(&tiles).ok_or(AocError::NoSolution)?;
+        Ok(water_roughness(&image).into())
+    }
+}
+
+impl Day for Day20 {
+    const DAY: u8 = 20;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tile {
+    pub id: u64,
+    pub cells: Grid<char>,
+}
+
+/// Parses a tile like `Tile 2311:` followed by its rows.
+fn parse_tile(paragraph: &str) -> Result<Tile, String> {
+    let (header, rows) = paragraph.split_once('\n').unwrap_or((paragraph, ""));
+
+    let id = header
+        .trim()
+        .strip_prefix("Tile ")
+        .and_then(|h| h.strip_suffix(':'))
+        .ok_or(format!("Expected `Tile <id>:`, found `{}`!", header))?
+        .parse()
+        .map_err(|e| format!("Bad tile ID in `{}`: {}", header, e))?;
+
+    let cells = rows
+        .parse::<Grid<char>>()
+        .map_err(|e| format!("Bad tile {}: {}", id, e))?;
+    if cells.width() != cells.height() {
+        return Err(format!("Tile {} isn't square!", id));
+    }
+
+    Ok(Tile { id, cells })
+}
+
+pub fn parse_tiles(input: &str) -> Result<Vec<Tile>, String> {
+    paragraphs(input).map(parse_tile).collect()
+}
+
+/// Turns a tile a quarter turn clockwise.
+fn rotate(tile: &Grid<char>) -> Grid<char> {
+    let (width, height) = (tile.width(), tile.height());
+    let cells = (0..width)
+        .flat_map(|y| (0..height).map(move |x| tile[(y, height - 1 - x)]))
+        .collect();
+
+    Grid::from_cells(cells, height, width)
+}
+
+/// Mirrors a tile left to right.
+fn flip(tile: &Grid<char>) -> Grid<char> {
+    let (width, height) = (tile.width(), tile.height());
+    let cells = (0..height)
+        .flat_map(|y| (0..width).map(move |x| tile[(width - 1 - x, y)]))
+        .collect();
+
+    Grid::from_cells(cells, width, height)
+}
+
+/// All eight ways a tile can be rotated and flipped: its four quarter turns,
+/// then the same four of its mirror image.
+fn orientations(tile: &Grid<char>) -> [Grid<char>; 8] {
+    let quarter_turns = |start: Grid<char>| {
+        let once = rotate(&start);
+        let twice = rotate(&once);
+        let thrice = rotate(&twice);
+        [start, once, twice, thrice]
+    };
+
+    let [a, b, c, d] = quarter_turns(tile.clone());
+    let [e, f, g, h] = quarter_turns(flip(tile));
+    [a, b, c, d, e, f, g, h]
+}
+
+fn top(tile: &Grid<char>) -> String {
+    tile.row(0).unwrap_or(&[]).iter().collect()
+}
+
+fn bottom(tile: &Grid<char>) -> String {
+    let last = tile.height().saturating_sub(1);
+    tile.row(last).unwrap_or(&[]).iter().collect()
+}
+
+fn left(tile: &Grid<char>) -> String {
+    (0..tile.height()).filter_map(|y| tile.get(0, y)).collect()
+}
+
+fn right(tile: &Grid<char>) -> String {
+    let last = tile.width().saturating_sub(1);
+    (0..tile.height())
+        .filter_map(|y| tile.get(last, y))
+        .collect()
+}
+
+/// The top, right, bottom, and left edges of a tile. Top and bottom read left
+/// to right, the sides read top to bottom.
+fn edges(tile: &Grid<char>) -> [String; 4] {
+    [top(tile), right(tile), bottom(tile), left(tile)]
+}
+
+/// An edge reads the same for both neighbors once one of them is flipped, so
+/// the smaller of it and its reverse identifies it either way round.
+fn edge_key(edge: String) -> String {
+    let reversed = edge.chars().rev().collect::<String>();
+    edge.min(reversed)
+}
+
+/// How many tiles share each edge, by `edge_key`.
+fn edge_counts(tiles: &[Tile]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+
+    for tile in tiles {
+        for edge in edges(&tile.cells) {
+            *counts.entry(edge_key(edge)).or_default() += 1;
+        }
+    }
+
+    counts
+}
+
+/// Corner tiles are the only ones with two edges that match no other tile.
+fn corners(tiles: &[Tile]) -> Vec<&Tile> {
+    let counts = edge_counts(tiles);
+    let unmatched = |edge: &String| counts[&edge_key(edge.clone())] == 1;
+
+    tiles
+        .iter()
+        .filter(|tile| edges(&tile.cells).iter().filter(|e| unmatched(e)).count() == 2)
+        .collect()
+}
+
+/// Part 1: the product of the four corner tile IDs.
+pub fn corner_product(tiles: &[Tile]) -> u64 {
+    corners(tiles).iter().map(|tile| tile.id).product()
+}
+
+/// Lays every tile out in its place, then strips their borders and joins them
+/// into a single image.
+///
+/// Starting from a corner turned so its unmatched edges face up and left, each
+/// following tile is the one that can be turned to line up with the tile to
+/// its left, or the one above it at the start of a row.
+pub fn assemble(tiles: &[Tile]) -> Option<Grid<char>> {
+    let counts = edge_counts(tiles);
+    let unmatched = |edge: String| counts[&edge_key(edge)] == 1;
+
+    let side = (1..=tiles.len()).find(|side| side * side == tiles.len())?;
+    let first = corners(tiles).first()?.id;
+
+    let mut unused = tiles.iter().collect::<Vec<&Tile>>();
+    let mut placed: Vec<Grid<char>> = Vec::with_capacity(tiles.len());
+
+    for spot in 0..tiles.len() {
+        let (row, column) = (spot / side, spot % side);
+
+        let fits = |tile: &Grid<char>| {
+            if spot == 0 {
+                unmatched(top(tile)) && unmatched(left(tile))
+            } else if column == 0 {
+                top(tile) == bottom(&placed[spot - side])
+            } else {
+                left(tile) == right(&placed[spot - 1])
+                    && (row == 0 || top(tile) == bottom(&placed[spot - side]))
+            }
+        };
+
+        let (index, oriented) = unused
+            .iter()
+            .enumerate()
+            .filter(|(_, tile)| spot != 0 || tile.id == first)
+            .find_map(|(index, tile)| {
+                // arrays only iterate by value with `IntoIterator` in edition 2018
+                IntoIterator::into_iter(orientations(&tile.cells))
+                    .find(|oriented| fits(oriented))
+                    .map(|oriented| (index, oriented))
+            })?;
+
+        unused.swap_remove(index);
+        placed.push(oriented);
+    }
+
+    let inner = placed[0].width() - 2;
+    let size = side * inner;
+    let mut image = Grid::from_cells(vec!['.'; size * size], size, size);
+    for (spot, tile) in placed.iter().enumerate() {
+        let (row, column) = (spot / side, spot % side);
+        for y in 0..inner {
+            for x in 0..inner {
+                image[(column * inner + x, row * inner + y)] = tile[(x + 1, y + 1)];
+            }
+        }
+    }
+
+    Some(image)
+}
+
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+/// Cells of the sea monster, as `(x, y)` offsets from its top left corner.
+fn monster_cells() -> Vec<(usize, usize)> {
+    SEA_MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|(_, c)| *c == '#')
+                .map(move |(x, _)| (x, y))
+        })
+        .collect()
+}
+
+/// An `(x, y)` spot in the image.
+type Spot = (usize, usize);
+
+/// Top left corners of every sea monster in `image`, as it's turned now.
+fn monster_corners(image: &Grid<char>, monster: &[Spot]) -> Vec<Spot> {
+    let monster_width = SEA_MONSTER[0].len();
+    let monster_height = SEA_MONSTER.len();
+    let (width, height) = (image.width(), image.height());
+
+    (0..(height + 1).saturating_sub(monster_height))
+        .flat_map(|y| (0..(width + 1).saturating_sub(monster_width)).map(move |x| (x, y)))
+        .filter(|&(x, y)| {
+            monster
+                .iter()
+                .all(|&(dx, dy)| image[(x + dx, y + dy)] == '#')
+        })
+        .collect()
+}
+
+/// The orientation of `image` with sea monsters in it, along with where they
+/// are. Only one orientation has any, so the first one found wins.
+fn find_monsters(image: &Grid<char>) -> Option<(Grid<char>, Vec<Spot>)> {
+    let monster = monster_cells();
+
+    IntoIterator::into_iter(orientations(image))
+        .map(|oriented| {
+            let corners = monster_corners(&oriented, &monster);
+            (oriented, corners)
+        })
+        .find(|(_, corners)| !corners.is_empty())
+}
+
+/// How many sea monsters are in the image, in whichever orientation has them.
+pub fn count_monsters(image: &Grid<char>) -> usize {
+    find_monsters(image).map_or(0, |(_, corners)| corners.len())
+}
+
+/// Part 2: counts the `#`s that aren't part of any sea monster. Where two
+/// monsters overlap, the `#`s they share are only taken away once.
+pub fn water_roughness(image: &Grid<char>) -> usize {
+    let total = image.cells().iter().filter(|&&cell| cell == '#').count();
+    let (oriented, corners) = match find_monsters(image) {
+        Some(found) => found,
+        None => return total,
+    };
+
+    let (width, height) = (oriented.width(), oriented.height());
+    let mut in_monster = Grid::from_cells(vec![false; width * height], width, height);
+    for (x, y) in corners {
+        for (dx, dy) in monster_cells() {
+            in_monster[(x + dx, y + dy)] = true;
+        }
+    }
+
+    total - in_monster.cells().iter().filter(|&&cell| cell).count()
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+
+    use crate::grid::GridError;
+
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day20::part1(SAMPLE)?, Answer::Int(20899048083289));
+        assert_eq!(Day20::part2(SAMPLE)?, Answer::Int(273));
+        Ok(())
+    }
+
+    const SAMPLE: &str = indoc! {"
+        Tile 2311:
+        ..##.#..#.
+        ##..#.....
+        #...##..#.
+        ####.#...#
+        ##.##.###.
+        ##...#.###
+        .#.#.#..##
+        ..#....#..
+        ###...#.#.
+        ..###..###
+
+        Tile 1951:
+        #.##...##.
+        #.####...#
+        .....#..##
+        #...######
+        .##.#....#
+        .###.#####
+        ###.##.##.
+        .###....#.
+        ..#.#..#.#
+        #...##.#..
+
+        Tile 1171:
+        ####...##.
+        #..##.#..#
+        ##.#..#.#.
+        .###.####.
+        ..###.####
+        .##....##.
+        .#...####.
+        #.##.####.
+        ####..#...
+        .....##...
+
+        Tile 1427:
+        ###.##.#..
+        .#..#.##..
+        .#.##.#..#
+        #.#.#.##.#
+        ....#...##
+        ...##..##.
+        ...#.#####
+        .#.####.#.
+        ..#..###.#
+        ..##.#..#.
+
+        Tile 1489:
+        ##.#.#....
+        ..##...#..
+        .##..##...
+        ..#...#...
+        #####...#.
+        #..#.#.#.#
+        ...#.#.#..
+        ##.#...##.
+        ..##.##.##
+        ###.##.#..
+
+        Tile 2473:
+        #....####.
+        #..#.##...
+        #.##..#...
+        ######.#.#
+        .#...#.#.#
+        .#########
+        .###.#..#.
+        ########.#
+        ##...##.#.
+        ..###.#.#.
+
+        Tile 2971:
+        ..#.#....#
+        #...###...
+        #.#.###...
+        ##.##..#..
+        .#####..##
+        .#..####.#
+        #..#.#..#.
+        ..####.###
+        ..#.#.###.
+        ...#.#.#.#
+
+        Tile 2729:
+        ...#.#.#.#
+        ####.#....
+        ..#.#.....
+        ....#..#.#
+        .##..##.#.
+        .#.####...
+        ####.#.#..
+        ##.####...
+        ##..#.##..
+        #.##...##.
+
+        Tile 3079:
+        #.#.#####.
+        .#..######
+        ..#.......
+        ######....
+        ####.#..#.
+        .#...#.##.
+        #.#####.##
+        ..#.###...
+        ..#.......
+        ..#.###...
+    "};
+
+    #[test]
+    fn parse_tiles_test() -> Result<(), String> {
+        let tiles = parse_tiles(SAMPLE)?;
+
+        assert_eq!(tiles.len(), 9);
+        assert_eq!(tiles[0].id, 2311);
+        assert_eq!(tiles[0].cells.width(), 10);
+        assert_eq!(tiles[0].cells.height(), 10);
+        assert_eq!(tiles[0].cells[(0, 0)], '.');
+        assert_eq!(tiles[0].cells[(2, 0)], '#');
+        Ok(())
+    }
+
+    #[test]
+    fn orientations_test() -> Result<(), GridError> {
+        let square = "#.\n..".parse::<Grid<char>>()?;
+        let all = orientations(&square);
+
+        assert_eq!(all.len(), 8);
+        assert_eq!(all[1], ".#\n..".parse()?);
+        assert_eq!(all[4], ".#\n..".parse()?);
+        assert_eq!(rotate(&rotate(&rotate(&rotate(&square)))), square);
+        Ok(())
+    }
+
+    #[test]
+    fn four_rotations_test() -> Result<(), String> {
+        let tile = parse_tiles(SAMPLE)?.remove(0).cells;
+
+        let turned = rotate(&tile);
+        assert_ne!(turned, tile);
+        assert_eq!(rotate(&rotate(&rotate(&turned))), tile);
+
+        // every orientation of an asymmetric tile is different
+        let all = orientations(&tile);
+        for (i, a) in all.iter().enumerate() {
+            assert!(all[i + 1..].iter().all(|b| a != b));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn edges_test() -> Result<(), String> {
+        let tile = parse_tiles(SAMPLE)?.remove(0).cells;
+        let [top, right, bottom, left] = edges(&tile);
+
+        assert_eq!(top, "..##.#..#.");
+        assert_eq!(right, "...#.##..#");
+        assert_eq!(bottom, "..###..###");
+        assert_eq!(left, ".#####..#.");
+
+        let reversed = |edge: &str| edge.chars().rev().collect::<String>();
+        let flipped = edges(&flip(&tile));
+        assert_eq!(flipped[0], reversed(&top));
+        assert_eq!(flipped[2], reversed(&bottom));
+        assert_eq!(flipped[1], left);
+        assert_eq!(flipped[3], right);
+        Ok(())
+    }
+
+    #[test]
+    fn corner_product_test() -> Result<(), String> {
+        let tiles = parse_tiles(SAMPLE)?;
+
+        assert_eq!(corner_product(&tiles), 20899048083289);
+        Ok(())
+    }
+
+    #[test]
+    fn no_monsters_test() -> Result<(), GridError> {
+        let image = "#.#\n.#.\n#.#".parse::<Grid<char>>()?;
+
+        assert_eq!(count_monsters(&image), 0);
+        assert_eq!(water_roughness(&image), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn water_roughness_test() -> Result<(), String> {
+        let tiles = parse_tiles(SAMPLE)?;
+        let image = assemble(&tiles).ok_or("Couldn't assemble the sample!")?;
+
+        assert_eq!(image.width(), 24);
+        assert_eq!(image.height(), 24);
+        assert_eq!(count_monsters(&image), 2);
+        assert_eq!(water_roughness(&image), 273);
+        Ok(())
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::{Answer, AocError, Day, Solver};
+
+/// Day 21: Allergen Assessment.
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Output = Answer;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        Ok(safe_appearances(&parse_foods(input).map_err(AocError::Parse)?).into())
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        dangerous_list(&parse_foods(input).map_err(AocError::Parse)?)
+            .map(Answer::from)
+            .ok_or(AocError::NoSolution)
+    }
+}
+
+impl Day for Day21 {
+    const DAY: u8 = 21;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Food<'s> {
+    pub ingredients: HashSet<&'s str>,
+    pub allergens: Vec<&'s str>,
+}
+
+/// Parses a line like `mxmxvkd kfcds sqjhc (contains dairy, fish)`.
+fn parse_food(line: &str) -> Result<Food<'_>, String> {
+    let (ingredients, allergens) = match line.split_once(" (contains ") {
+        Some((ingredients, allergens)) => (
+            ingredients,
+            allergens
+                .strip_suffix(')')
+                .ok_or(format!("Unclosed allergen list in `{}`!", line))?,
+        ),
+        None => (line, ""),
+    };
+
+    Ok(Food {
+        ingredients: ingredients.split_whitespace().collect(),
+        allergens: allergens
+            .split(',')
+            .map(str::trim)
+            .filter(|allergen| !allergen.is_empty())
+            .collect(),
+    })
+}
+
+pub fn parse_foods(input: &str) -> Result<Vec<Food<'_>>, String> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(parse_food)
+        .collect()
+}
+
+/// A food's ingredients, then its allergens.
+type FoodSets<'s> = (HashSet<&'s str>, HashSet<&'s str>);
+
+fn food_sets<'s>(foods: &[Food<'s>]) -> Vec<FoodSets<'s>> {
+    foods
+        .iter()
+        .map(|food| {
+            let allergens = food.allergens.iter().copied().collect();
+            (food.ingredients.clone(), allergens)
+        })
+        .collect()
+}
+
+/// The ingredients that might contain each allergen: only those in every food
+/// listing it.
+fn candidates<'s>(foods: &[FoodSets<'s>]) -> BTreeMap<&'s str, HashSet<&'s str>> {
+    let mut candidates: BTreeMap<&str, HashSet<&str>> = BTreeMap::new();
+
+    for (ingredients, allergens) in foods {
+        for &allergen in allergens.iter() {
+            candidates
+                .entry(allergen)
+                .and_modify(|possible| possible.retain(|i| ingredients.contains(i)))
+                .or_insert_with(|| ingredients.clone());
+        }
+    }
+
+    candidates
+}
+
+/// Part 1: how many times do ingredients that can't hold any allergen appear?
+pub fn safe_appearances(foods: &[Food]) -> usize {
+    let unsafe_ingredients = candidates(&food_sets(foods))
+        .into_values()
+        .flatten()
+        .collect::<HashSet<&str>>();
+
+    foods
+        .iter()
+        .flat_map(|food| food.ingredients.iter())
+        .filter(|ingredient| !unsafe_ingredients.contains(*ingredient))
+        .count()
+}
+
+/// Pins each allergen to the one ingredient that contains it, the same way
+/// day 16 pins fields to columns.
+///
+/// Any allergen down to a single candidate claims it, which takes it away
+/// from all the others. If the foods don't pin every allergen down, only the
+/// ones they do are returned.
+fn resolve_allergens<'s>(foods: &[FoodSets<'s>]) -> HashMap<&'s str, &'s str> {
+    let mut candidates = candidates(foods);
+    let mut pinned = HashMap::new();
+
+    while let Some((allergen, ingredient)) = candidates
+        .iter()
+        .find(|(_, possible)| possible.len() == 1)
+        .and_then(|(&allergen, possible)| Some((allergen, *possible.iter().next()?)))
+    {
+        candidates.remove(allergen);
+        for possible in candidates.values_mut() {
+            possible.remove(ingredient);
+        }
+        pinned.insert(allergen, ingredient);
+    }
+
+    pinned
+}
+
+/// Part 2: the dangerous ingredients, ordered by the allergen each contains.
+pub fn dangerous_list(foods: &[Food]) -> Option<String> {
+    let foods = food_sets(foods);
+    let pinned = resolve_allergens(&foods);
+    if pinned.len() != candidates(&foods).len() {
+        return None;
+    }
+
+    let by_allergen = pinned.into_iter().collect::<BTreeMap<&str, &str>>();
+    Some(
+        by_allergen
+            .values()
+            .copied()
+            .collect::<Vec<&str>>()
+            .join(","),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day21::part1(SAMPLE)?, Answer::Int(5));
+        assert_eq!(Day21::part2(SAMPLE)?, Answer::from("mxmxvkd,sqjhc,fvjkl"));
+        Ok(())
+    }
+
+    const SAMPLE: &str = indoc! {"
+        mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+        trh fvjkl sbzzf mxmxvkd (contains dairy)
+        sqjhc fvjkl (contains soy)
+        sqjhc mxmxvkd sbzzf (contains fish)
+    "};
+
+    #[test]
+    fn parse_food_test() -> Result<(), String> {
+        let food = parse_food("sqjhc fvjkl (contains soy)")?;
+
+        assert_eq!(
+            food.ingredients,
+            vec!["sqjhc", "fvjkl"].into_iter().collect()
+        );
+        assert_eq!(food.allergens, vec!["soy"]);
+        assert_eq!(parse_food("abc def")?.allergens, Vec::<&str>::new());
+        assert!(parse_food("abc (contains soy").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn safe_appearances_test() -> Result<(), String> {
+        let foods = parse_foods(SAMPLE)?;
+
+        assert_eq!(safe_appearances(&foods), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_allergens_test() -> Result<(), String> {
+        let foods = food_sets(&parse_foods(SAMPLE)?);
+        let pinned = resolve_allergens(&foods);
+
+        assert_eq!(pinned.len(), 3);
+        assert_eq!(pinned["dairy"], "mxmxvkd");
+        assert_eq!(pinned["fish"], "sqjhc");
+        assert_eq!(pinned["soy"], "fvjkl");
+        Ok(())
+    }
+
+    #[test]
+    fn unresolvable_allergens_test() -> Result<(), String> {
+        let foods = parse_foods("abc def (contains soy)\n")?;
+
+        assert!(resolve_allergens(&food_sets(&foods)).is_empty());
+        assert_eq!(dangerous_list(&foods), None);
+        Ok(())
+    }
+
+    #[test]
+    fn dangerous_list_test() -> Result<(), String> {
+        let foods = parse_foods(SAMPLE)?;
+
+        assert_eq!(
+            dangerous_list(&foods),
+            Some("mxmxvkd,sqjhc,fvjkl".to_string())
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+
+use crate::{paragraphs, AocError, Day, Solver};
+
+/// Day 22: Crab Combat.
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Output = u64;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        let (one, two) = parse_decks(input).map_err(AocError::Parse)?;
+        Ok(combat(one, two))
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        let (one, two) = parse_decks(input).map_err(AocError::Parse)?;
+        Ok(recursive_combat(one, two))
+    }
+}
+
+impl Day for Day22 {
+    const DAY: u8 = 22;
+}
+
+pub type Deck = VecDeque<u8>;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Player {
+    One,
+    Two,
+}
+
+/// Parses a deck like `Player 1:` followed by one card per line, top first.
+fn parse_deck(paragraph: &str) -> Result<Deck, String> {
+    let mut lines = paragraph.lines();
+
+    match lines.next() {
+        Some(header) if header.starts_with("Player") => {}
+        other => return Err(format!("Expected a player header, found {:?}!", other)),
+    }
+
+    lines
+        .map(|card| {
+            card.trim()
+                .parse()
+                .map_err(|e| format!("Bad card `{}`: {}", card, e))
+        })
+        .collect()
+}
+
+pub fn parse_decks(input: &str) -> Result<(Deck, Deck), String> {
+    let mut decks = paragraphs(input).map(parse_deck);
+
+    match (decks.next(), decks.next()) {
+        (Some(one), Some(two)) => Ok((one?, two?)),
+        _ => Err("Expected two decks!".to_string()),
+    }
+}
+
+/// The bottom card is worth its value, the next its value times two, and so
+/// on up to the top of the deck.
+fn score(deck: &Deck) -> u64 {
+    deck.iter()
+        .rev()
+        .zip(1..)
+        .map(|(&card, position)| card as u64 * position)
+        .sum()
+}
+
+/// The winner of a round puts their own card under their deck first.
+fn take_cards(winner: Player, one: &mut Deck, two: &mut Deck, first: u8, second: u8) {
+    match winner {
+        Player::One => one.extend([first, second]),
+        Player::Two => two.extend([second, first]),
+    }
+}
+
+/// Part 1: the higher card takes both, until one player holds every card.
+pub fn combat(mut one: Deck, mut two: Deck) -> u64 {
+    while let (Some(&first), Some(&second)) = (one.front(), two.front()) {
+        one.pop_front();
+        two.pop_front();
+
+        let winner = if first > second {
+            Player::One
+        } else {
+            Player::Two
+        };
+        take_cards(winner, &mut one, &mut two, first, second);
+    }
+
+    score(if two.is_empty() { &one } else { &two })
+}
+
+/// Stands in for a pair of decks in the set of rounds already played, so
+/// every round doesn't have to clone both of them.
+///
+/// Each deck hashes its length along with its cards, so where one deck ends
+/// and the other begins is part of the snapshot. Two different pairs could
+/// still share a hash, but with 64 bits that's vanishingly unlikely over the
+/// few thousand rounds a game lasts.
+fn snapshot(one: &Deck, two: &Deck) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    one.hash(&mut hasher);
+    two.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Plays a game of Recursive Combat, returning the winner and their deck.
+///
+/// Seeing the same pair of decks twice in one game means it would go on
+/// forever, so player one wins outright instead.
+fn recursive_game(mut one: Deck, mut two: Deck) -> (Player, Deck) {
+    let mut seen = HashSet::new();
+
+    while let (Some(&first), Some(&second)) = (one.front(), two.front()) {
+        if !seen.insert(snapshot(&one, &two)) {
+            return (Player::One, one);
+        }
+        one.pop_front();
+        two.pop_front();
+
+        let winner = if one.len() >= first as usize && two.len() >= second as usize {
+            let sub_one = one.iter().take(first as usize).copied().collect();
+            let sub_two = two.iter().take(second as usize).copied().collect();
+            recursive_game(sub_one, sub_two).0
+        } else if first > second {
+            Player::One
+        } else {
+            Player::Two
+        };
+        take_cards(winner, &mut one, &mut two, first, second);
+    }
+
+    if two.is_empty() {
+        (Player::One, one)
+    } else {
+        (Player::Two, two)
+    }
+}
+
+/// Part 2: the winning score of a game of Recursive Combat.
+pub fn recursive_combat(one: Deck, two: Deck) -> u64 {
+    score(&recursive_game(one, two).1)
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day22::part1(SAMPLE)?, 306);
+        assert_eq!(Day22::part2(SAMPLE)?, 291);
+        Ok(())
+    }
+
+    const SAMPLE: &str = indoc! {"
+        Player 1:
+        9
+        2
+        6
+        3
+        1
+
+        Player 2:
+        5
+        8
+        4
+        7
+        10
+    "};
+
+    #[test]
+    fn parse_decks_test() -> Result<(), String> {
+        let (one, two) = parse_decks(SAMPLE)?;
+
+        assert_eq!(one, vec![9, 2, 6, 3, 1]);
+        assert_eq!(two, vec![5, 8, 4, 7, 10]);
+        Ok(())
+    }
+
+    #[test]
+    fn combat_test() -> Result<(), String> {
+        let (one, two) = parse_decks(SAMPLE)?;
+
+        assert_eq!(combat(one, two), 306);
+        Ok(())
+    }
+
+    #[test]
+    fn recursive_combat_test() -> Result<(), String> {
+        let (one, two) = parse_decks(SAMPLE)?;
+
+        assert_eq!(recursive_combat(one, two), 291);
+        Ok(())
+    }
+
+    #[test]
+    fn repeated_rounds_test() {
+        let one = vec![43, 19].into_iter().collect();
+        let two = vec![2, 29, 14].into_iter().collect();
+
+        assert_eq!(recursive_game(one, two).0, Player::One);
+    }
+
+    #[test]
+    fn snapshot_test() {
+        let deck = |cards: &[u8]| cards.iter().copied().collect::<Deck>();
+
+        assert_eq!(
+            snapshot(&deck(&[1, 2]), &deck(&[3])),
+            snapshot(&deck(&[1, 2]), &deck(&[3]))
+        );
+        assert_ne!(
+            snapshot(&deck(&[1, 2]), &deck(&[3])),
+            snapshot(&deck(&[1]), &deck(&[2, 3]))
+        );
+        assert_ne!(
+            snapshot(&deck(&[1, 2]), &deck(&[3])),
+            snapshot(&deck(&[3]), &deck(&[1, 2]))
+        );
+    }
+}
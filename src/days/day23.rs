@@ -0,0 +1,288 @@
+use crate::{Answer, AocError, Day, Solver};
+
+/// Day 23: Crab Cups.
+pub struct Day23;
+
+impl Solver for Day23 {
+    type Output = Answer;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        Ok(labels_after_one(&parse_labels(input).map_err(AocError::Parse)?, 100).into())
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        Ok(star_cups(&parse_labels(input).map_err(AocError::Parse)?).into())
+    }
+}
+
+impl Day for Day23 {
+    const DAY: u8 = 23;
+}
+
+/// A move picks up three cups and needs one more on the table to set them
+/// down after, so smaller circles can't be played.
+const MIN_CUPS: usize = 4;
+
+/// Reads one cup label per digit. The labels have to be `1..=n` in some
+/// order, since every cup in the circle needs exactly one label, and there
+/// have to be at least `MIN_CUPS` of them.
+pub fn parse_labels(input: &str) -> Result<Vec<u32>, String> {
+    let labels = input
+        .trim()
+        .chars()
+        .map(|c| c.to_digit(10).ok_or(format!("Bad cup label `{}`!", c)))
+        .collect::<Result<Vec<u32>, String>>()?;
+
+    if !is_permutation(&labels) {
+        return Err(format!(
+            "Cup labels should be 1 to {} with each used once, but found `{}`!",
+            labels.len(),
+            input.trim()
+        ));
+    }
+    if labels.len() < MIN_CUPS {
+        return Err(format!(
+            "Need at least {} cup labels to play, but found `{}`!",
+            MIN_CUPS,
+            input.trim()
+        ));
+    }
+    Ok(labels)
+}
+
+/// Whether `labels` holds every number in `1..=labels.len()` exactly once.
+fn is_permutation(labels: &[u32]) -> bool {
+    let mut sorted = labels.to_vec();
+    sorted.sort_unstable();
+    sorted.into_iter().eq(1..=labels.len() as u32)
+}
+
+/// A circle of cups labelled `1..=len`, stored as a "next cup" pointer per
+/// label so a move only relinks a handful of cups instead of shifting them.
+struct CrabCups {
+    /// `next[i]` is the label of the cup clockwise of cup `i + 1`, since labels
+    /// start at one.
+    next: Vec<u32>,
+    current: u32,
+}
+
+impl CrabCups {
+    /// Places the `labels` in order, then fills the circle out with every
+    /// label after the largest up to `total`.
+    ///
+    /// Panics unless the `labels` are `1..=n` in some order, with `total` at
+    /// least `n` and at least `MIN_CUPS`.
+    fn new(labels: &[u32], total: u32) -> Self {
+        assert!(
+            is_permutation(labels),
+            "Cup labels should be 1 to {} with each used once, not {:?}",
+            labels.len(),
+            labels
+        );
+        assert!(
+            total as usize >= labels.len(),
+            "Can't fit {} labelled cups into a circle of {}",
+            labels.len(),
+            total
+        );
+        assert!(
+            total as usize >= MIN_CUPS,
+            "Need at least {} cups to play, not {}",
+            MIN_CUPS,
+            total
+        );
+
+        let mut order = labels.to_vec();
+        let largest = labels.iter().copied().max().unwrap_or(0);
+        order.extend(largest + 1..=total);
+
+        let mut next = vec![0; order.len()];
+        for pair in order.windows(2) {
+            next[pair[0] as usize - 1] = pair[1];
+        }
+        if let (Some(&first), Some(&last)) = (order.first(), order.last()) {
+            next[last as usize - 1] = first;
+        }
+
+        Self {
+            next,
+            current: order.first().copied().unwrap_or(0),
+        }
+    }
+
+    fn len(&self) -> u32 {
+        self.next.len() as u32
+    }
+
+    fn after(&self, label: u32) -> u32 {
+        self.next[label as usize - 1]
+    }
+
+    fn set_after(&mut self, label: u32, next: u32) {
+        self.next[label as usize - 1] = next;
+    }
+
+    /// Picks up the three cups after the current one, drops them after the
+    /// next lower label still on the table, and moves along one cup.
+    fn play_move(&mut self) {
+        let first = self.after(self.current);
+        let second = self.after(first);
+        let third = self.after(second);
+        let picked_up = [first, second, third];
+
+        let mut destination = self.current;
+        loop {
+            destination = if destination == 1 {
+                self.len()
+            } else {
+                destination - 1
+            };
+            if !picked_up.contains(&destination) {
+                break;
+            }
+        }
+
+        self.set_after(self.current, self.after(third));
+        self.set_after(third, self.after(destination));
+        self.set_after(destination, first);
+        self.current = self.after(self.current);
+    }
+
+    fn play(&mut self, moves: usize) {
+        for _ in 0..moves {
+            self.play_move();
+        }
+    }
+
+    /// Every label clockwise from cup 1, not including cup 1 itself.
+    fn labels_after_one(&self) -> impl Iterator<Item = u32> + '_ {
+        let mut cup = 1;
+        std::iter::from_fn(move || {
+            cup = self.after(cup);
+            if cup == 1 {
+                None
+            } else {
+                Some(cup)
+            }
+        })
+    }
+}
+
+/// Part 1: the labels clockwise from cup 1, after 100 moves of a small circle.
+pub fn labels_after_one(labels: &[u32], moves: usize) -> String {
+    let mut cups = CrabCups::new(labels, labels.len() as u32);
+    cups.play(moves);
+
+    cups.labels_after_one().map(|cup| cup.to_string()).collect()
+}
+
+/// Part 2: a million cups and ten million moves, then the two cups after cup 1
+/// multiplied together.
+pub fn star_cups(labels: &[u32]) -> u64 {
+    let mut cups = CrabCups::new(labels, 1_000_000);
+    cups.play(10_000_000);
+
+    cups.labels_after_one().take(2).map(u64::from).product()
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::timed_value;
+
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day23::part1("389125467\n")?, Answer::from("67384529"));
+        assert!(matches!(Day23::part1("3120"), Err(AocError::Parse(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn circle_test() {
+        let cups = CrabCups::new(&[3, 8, 9, 1, 2, 5, 4, 6, 7], 12);
+
+        assert_eq!(cups.len(), 12);
+        assert_eq!(cups.current, 3);
+        assert_eq!(cups.after(7), 10);
+        assert_eq!(cups.after(12), 3);
+        assert_eq!(
+            cups.labels_after_one().collect::<Vec<u32>>(),
+            vec![2, 5, 4, 6, 7, 10, 11, 12, 3, 8, 9]
+        );
+    }
+
+    #[test]
+    fn labels_after_one_test() -> Result<(), String> {
+        let labels = parse_labels("389125467\n")?;
+
+        assert_eq!(labels_after_one(&labels, 10), "92658374");
+        assert_eq!(labels_after_one(&labels, 100), "67384529");
+        Ok(())
+    }
+
+    #[test]
+    fn bad_labels_test() {
+        assert_eq!(parse_labels("312x"), Err("Bad cup label `x`!".to_string()));
+        assert_eq!(
+            parse_labels("3120\n"),
+            Err("Cup labels should be 1 to 4 with each used once, but found `3120`!".to_string())
+        );
+        assert_eq!(
+            parse_labels("3121"),
+            Err("Cup labels should be 1 to 4 with each used once, but found `3121`!".to_string())
+        );
+        assert_eq!(
+            parse_labels("315"),
+            Err("Cup labels should be 1 to 3 with each used once, but found `315`!".to_string())
+        );
+    }
+
+    #[test]
+    fn too_few_labels_test() {
+        assert_eq!(
+            parse_labels("1"),
+            Err("Need at least 4 cup labels to play, but found `1`!".to_string())
+        );
+        assert_eq!(
+            parse_labels("21\n"),
+            Err("Need at least 4 cup labels to play, but found `21`!".to_string())
+        );
+        assert_eq!(
+            parse_labels(""),
+            Err("Need at least 4 cup labels to play, but found ``!".to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't fit 5 labelled cups")]
+    fn circle_too_small_test() {
+        CrabCups::new(&[3, 1, 2, 5, 4], 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Need at least 4 cups")]
+    fn too_few_cups_test() {
+        CrabCups::new(&[2, 1], 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "each used once")]
+    fn duplicate_labels_test() {
+        CrabCups::new(&[3, 1, 3], 5);
+    }
+
+    #[test]
+    fn star_cups_test() -> Result<(), String> {
+        let labels = parse_labels("389125467\n")?;
+        let (product, elapsed) = timed_value(|| star_cups(&labels));
+
+        assert_eq!(product, 149245887792);
+        // well over what it needs even unoptimized, but shifting a million
+        // cups around ten million times would never make it
+        assert!(elapsed < Duration::from_secs(30), "took {:?}", elapsed);
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::{HashMap, HashSet};
+
+use logos::{Lexer, Logos};
+
+use crate::{AocError, Day, Solver};
+
+/// Day 24: Lobby Layout.
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Output = usize;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        Ok(black_tiles(input)?.len())
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        Ok(after_days(&black_tiles(input)?, 100).len())
+    }
+}
+
+impl Day for Day24 {
+    const DAY: u8 = 24;
+}
+
+fn black_tiles(input: &str) -> Result<HashSet<Hex>, AocError> {
+    flip_tiles(&mut Step::lexer(input)).map_err(AocError::Parse)
+}
+
+#[derive(Logos, Debug, PartialEq, Clone, Copy)]
+pub enum Step {
+    #[token("e")]
+    East,
+
+    #[token("w")]
+    West,
+
+    #[token("ne")]
+    NorthEast,
+
+    #[token("nw")]
+    NorthWest,
+
+    #[token("se")]
+    SouthEast,
+
+    #[token("sw")]
+    SouthWest,
+
+    #[token("\n")]
+    LineEnd,
+
+    // Logos requires one token variant to handle errors,
+    // it can be named anything you wish.
+    #[error]
+    // We can also use this variant to define whitespace,
+    // or any other matches we wish to skip.
+    #[regex(r"[ \t\f]+", logos::skip)]
+    Error,
+}
+
+/// Hex tiles in axial coordinates `(q, r)`: `q` grows to the east and `r`
+/// grows to the south east, so every tile has exactly six neighbors.
+pub type Hex = (i32, i32);
+
+const NEIGHBORS: [Hex; 6] = [(1, 0), (-1, 0), (1, -1), (0, -1), (0, 1), (-1, 1)];
+
+impl Step {
+    fn offset(self) -> Option<Hex> {
+        match self {
+            Step::East => Some(NEIGHBORS[0]),
+            Step::West => Some(NEIGHBORS[1]),
+            Step::NorthEast => Some(NEIGHBORS[2]),
+            Step::NorthWest => Some(NEIGHBORS[3]),
+            Step::SouthEast => Some(NEIGHBORS[4]),
+            Step::SouthWest => Some(NEIGHBORS[5]),
+            Step::LineEnd | Step::Error => None,
+        }
+    }
+}
+
+/// Follows each line of steps from the reference tile and flips the tile it
+/// ends on, returning the tiles left black side up.
+pub fn flip_tiles(tokens: &mut Lexer<Step>) -> Result<HashSet<Hex>, String> {
+    let mut black = HashSet::new();
+    let mut tile = (0, 0);
+    let mut steps = 0;
+
+    let mut flip = |tile: Hex| {
+        if !black.remove(&tile) {
+            black.insert(tile);
+        }
+    };
+
+    while let Some(step) = tokens.next() {
+        match step.offset() {
+            Some((dq, dr)) => {
+                tile = (tile.0 + dq, tile.1 + dr);
+                steps += 1;
+            }
+            None if step == Step::LineEnd => {
+                if steps > 0 {
+                    flip(tile);
+                }
+                tile = (0, 0);
+                steps = 0;
+            }
+            None => {
+                return Err(format!(
+                    "Expected a direction but found `{}` at {:?}!",
+                    tokens.slice(),
+                    tokens.span()
+                ))
+            }
+        }
+    }
+    if steps > 0 {
+        flip(tile);
+    }
+
+    Ok(black)
+}
+
+/// Runs one day of the art exhibit: black tiles with zero or more than two
+/// black neighbors flip to white, and white tiles with exactly two flip to
+/// black.
+fn next_day(black: &HashSet<Hex>) -> HashSet<Hex> {
+    let mut black_neighbors: HashMap<Hex, usize> = HashMap::new();
+
+    for &(q, r) in black {
+        for &(dq, dr) in NEIGHBORS.iter() {
+            *black_neighbors.entry((q + dq, r + dr)).or_default() += 1;
+        }
+    }
+
+    black_neighbors
+        .into_iter()
+        .filter(|(tile, count)| *count == 2 || (*count == 1 && black.contains(tile)))
+        .map(|(tile, _)| tile)
+        .collect()
+}
+
+pub fn after_days(black: &HashSet<Hex>, days: usize) -> HashSet<Hex> {
+    (0..days).fold(black.clone(), |black, _| next_day(&black))
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day24::part1(SAMPLE)?, 10);
+        assert_eq!(Day24::part2(SAMPLE)?, 2208);
+        Ok(())
+    }
+
+    const SAMPLE: &str = indoc! {"
+        sesenwnenenewseeswwswswwnenewsewsw
+        neeenesenwnwwswnenewnwwsewnenwseswesw
+        seswneswswsenwwnwse
+        nwnwneseeswswnenewneswwnewseswneseene
+        swweswneswnenwsewnwneneseenw
+        eesenwseswswnenwswnwnwsewwnwsene
+        sewnenenenesenwsewnenwwwse
+        wenwwweseeeweswwwnwwe
+        wsweesenenewnwwnwsenewsenwwsesesenwne
+        neeswseenwwswnwswswnw
+        nenwswwsewswnenenewsenwsenwnesesenew
+        enewnwewneswsewnwswenweswnenwsenwsw
+        sweneswneswneneenwnewenewwneswswnese
+        swwesenesewenwneswnwwneseswwne
+        enesenwswwswneneswsenwnewswseenwsese
+        wnwnesenesenenwwnenwsewesewsesesew
+        nenewswnwewswnenesenwnesewesw
+        eneswnwswnwsenenwnwnwwseeswneewsenese
+        neswnwewnwnwseenwseesewsenwsweewe
+        wseweeenwnesenwwwswnew
+    "};
+
+    #[test]
+    fn step_lexing_test() {
+        let mut lex = Step::lexer("esenee\n");
+
+        assert_eq!(lex.next(), Some(Step::East));
+        assert_eq!(lex.next(), Some(Step::SouthEast));
+        assert_eq!(lex.next(), Some(Step::NorthEast));
+        assert_eq!(lex.next(), Some(Step::East));
+        assert_eq!(lex.next(), Some(Step::LineEnd));
+        assert_eq!(lex.next(), None);
+    }
+
+    #[test]
+    fn back_to_reference_test() -> Result<(), String> {
+        let black = flip_tiles(&mut Step::lexer("nwwswee"))?;
+
+        assert_eq!(black, vec![(0, 0)].into_iter().collect());
+        Ok(())
+    }
+
+    #[test]
+    fn flip_tiles_test() -> Result<(), String> {
+        let black = flip_tiles(&mut Step::lexer(SAMPLE))?;
+
+        assert_eq!(black.len(), 10);
+        Ok(())
+    }
+
+    #[test]
+    fn after_days_test() -> Result<(), String> {
+        let black = flip_tiles(&mut Step::lexer(SAMPLE))?;
+
+        assert_eq!(after_days(&black, 1).len(), 15);
+        assert_eq!(after_days(&black, 10).len(), 37);
+        assert_eq!(after_days(&black, 100).len(), 2208);
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{Answer, AocError, Day, Solver};
+
+/// Day 25: Combo Breaker.
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Output = Answer;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        let (card, door) = parse_keys(input).map_err(AocError::Parse)?;
+        encryption_key(card, door)
+            .map(Answer::from)
+            .ok_or(AocError::NoSolution)
+    }
+
+    /// There's no second puzzle on the last day, the final star comes free
+    /// with the other 49.
+    fn part2(_: &str) -> Result<Self::Output, AocError> {
+        Ok(Answer::from("Merry Christmas!"))
+    }
+}
+
+impl Day for Day25 {
+    const DAY: u8 = 25;
+}
+
+const MODULUS: u64 = 20201227;
+const SUBJECT: u64 = 7;
+
+pub fn parse_keys(input: &str) -> Result<(u64, u64), String> {
+    let mut keys = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|key| {
+            key.parse()
+                .map_err(|e| format!("Bad public key `{}`: {}", key, e))
+        });
+
+    match (keys.next(), keys.next()) {
+        (Some(card), Some(door)) => Ok((card?, door?)),
+        _ => Err("Expected two public keys!".to_string()),
+    }
+}
+
+/// `base` raised to `exponent`, modulo `MODULUS`, by repeated squaring.
+fn mod_pow(mut base: u64, mut exponent: u64) -> u64 {
+    let mut result = 1;
+    base %= MODULUS;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % MODULUS;
+        }
+        base = base * base % MODULUS;
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// Finds how many times the subject number 7 was transformed to get
+/// `public_key`, by running the transform until it turns up.
+fn loop_size(public_key: u64) -> Option<u64> {
+    let mut value = 1;
+
+    for size in 0..MODULUS {
+        if value == public_key {
+            return Some(size);
+        }
+        value = value * SUBJECT % MODULUS;
+    }
+
+    None
+}
+
+/// Part 1: either device's loop size, applied to the other's public key.
+pub fn encryption_key(card: u64, door: u64) -> Option<u64> {
+    Some(mod_pow(door, loop_size(card)?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn solver_test() -> Result<(), AocError> {
+        assert_eq!(Day25::part1("5764801\n17807724\n")?, Answer::Int(14897079));
+        assert!(matches!(Day25::part1("5764801\n"), Err(AocError::Parse(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn loop_size_test() {
+        assert_eq!(loop_size(5764801), Some(8));
+        assert_eq!(loop_size(17807724), Some(11));
+        assert_eq!(mod_pow(SUBJECT, 8), 5764801);
+    }
+
+    #[test]
+    fn encryption_key_test() -> Result<(), String> {
+        let (card, door) = parse_keys("5764801\n17807724\n")?;
+
+        assert_eq!(encryption_key(card, door), Some(14897079));
+        assert_eq!(encryption_key(door, card), Some(14897079));
+        Ok(())
+    }
+}
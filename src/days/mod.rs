@@ -0,0 +1,74 @@
+//! Solutions for each day that can be run from the command line.
+
+use crate::{parts, run, Answer, AocError};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Every day with a solution, in order.
+pub const SOLVED: &[u8] = &[
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+];
+
+/// Solves one `part` of `day` by number, or both parts when `part` is `None`.
+pub fn solve(day: u8, part: Option<u8>, input: &str) -> Result<Vec<(u8, Answer)>, AocError> {
+    parts(part)
+        .into_iter()
+        .map(|part| solve_part(day, part, input).map(|answer| (part, answer)))
+        .collect()
+}
+
+/// Solves exactly one `part` of `day` by number.
+pub fn solve_part(day: u8, part: u8, input: &str) -> Result<Answer, AocError> {
+    match day {
+        1 => run::<day01::Day01>(part, input),
+        2 => run::<day02::Day02>(part, input),
+        3 => run::<day03::Day03>(part, input),
+        4 => run::<day04::Day04>(part, input),
+        5 => run::<day05::Day05>(part, input),
+        6 => run::<day06::Day06>(part, input),
+        7 => run::<day07::Day07>(part, input),
+        8 => run::<day08::Day08>(part, input),
+        9 => run::<day09::Day09>(part, input),
+        10 => run::<day10::Day10>(part, input),
+        11 => run::<day11::Day11>(part, input),
+        12 => run::<day12::Day12>(part, input),
+        13 => run::<day13::Day13>(part, input),
+        14 => run::<day14::Day14>(part, input),
+        15 => run::<day15::Day15>(part, input),
+        16 => run::<day16::Day16>(part, input),
+        17 => run::<day17::Day17>(part, input),
+        18 => run::<day18::Day18>(part, input),
+        19 => run::<day19::Day19>(part, input),
+        20 => run::<day20::Day20>(part, input),
+        21 => run::<day21::Day21>(part, input),
+        22 => run::<day22::Day22>(part, input),
+        23 => run::<day23::Day23>(part, input),
+        24 => run::<day24::Day24>(part, input),
+        25 => run::<day25::Day25>(part, input),
+        _ => Err(AocError::UnknownDay(day)),
+    }
+}
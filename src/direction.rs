@@ -0,0 +1,104 @@
+use crate::point::Point;
+
+/// A compass heading, for walking around a plane a quarter turn at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise from north, so a right turn is one step along.
+    const CLOCKWISE: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        self.turn(-90)
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.turn(90)
+    }
+
+    /// Turns clockwise by `degrees`, or counter-clockwise when it's negative.
+    /// Panics unless `degrees` is a multiple of 90.
+    pub fn turn(self, degrees: i32) -> Self {
+        assert!(
+            degrees % 90 == 0,
+            "Can only turn by multiples of 90 degrees, not {}",
+            degrees
+        );
+
+        let quarters = (degrees / 90).rem_euclid(4) as usize;
+        Self::CLOCKWISE[(self as usize + quarters) % 4]
+    }
+
+    /// A single step in this direction, with east along positive `x` and
+    /// north along positive `y`.
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, 1),
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, -1),
+            Direction::West => Point::new(-1, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn turning_right() {
+        let mut heading = Direction::North;
+        for _ in 0..4 {
+            heading = heading.turn_right();
+        }
+        assert_eq!(heading, Direction::North);
+
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+    }
+
+    #[test]
+    fn turning_left() {
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::East.turn_left(), Direction::North);
+    }
+
+    #[test]
+    fn turning_by_degrees() {
+        assert_eq!(Direction::East.turn(90), Direction::South);
+        assert_eq!(Direction::East.turn(270), Direction::North);
+        assert_eq!(Direction::East.turn(-90), Direction::North);
+        assert_eq!(Direction::East.turn(-180), Direction::West);
+        assert_eq!(Direction::East.turn(360), Direction::East);
+        assert_eq!(Direction::East.turn(0), Direction::East);
+    }
+
+    #[test]
+    #[should_panic]
+    fn turning_off_axis() {
+        Direction::North.turn(45);
+    }
+
+    #[test]
+    fn deltas() {
+        assert_eq!(Direction::East.delta(), Point::new(1, 0));
+        assert_eq!(Direction::North.delta(), Point::new(0, 1));
+
+        let back_home = Direction::CLOCKWISE
+            .iter()
+            .fold(Point::ORIGIN, |position, heading| {
+                position + heading.delta()
+            });
+        assert_eq!(back_home, Point::ORIGIN);
+    }
+}
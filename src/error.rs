@@ -0,0 +1,75 @@
+use std::io;
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Everything that can go wrong while loading and solving a puzzle.
+#[derive(Debug, Error)]
+pub enum AocError {
+    #[error("Could not access puzzle input: {0}")]
+    Io(#[from] io::Error),
+    /// The puzzle input at `path` couldn't be read.
+    #[error("Could not read {}: {source}", path.display())]
+    Input { path: PathBuf, source: io::Error },
+    /// Talking to adventofcode.com failed.
+    #[error("Could not fetch from Advent of Code: {0}")]
+    Fetch(String),
+    /// The puzzle input couldn't be understood.
+    #[error("Could not parse puzzle input: {0}")]
+    Parse(String),
+    /// The input parsed, but the puzzle has no answer for it.
+    #[error("No solution found for this input")]
+    NoSolution,
+    #[error("Day {0} doesn't exist, try 1 to 25")]
+    UnknownDay(u8),
+    #[error("Part {0} doesn't exist, try 1 or 2")]
+    UnknownPart(u8),
+    /// This many answers didn't match the expected ones.
+    #[error("Wrong answers found: {0}")]
+    WrongAnswers(usize),
+    /// The crate was built without a feature this needs.
+    #[error("Rebuild with the `{0}` feature to do this")]
+    MissingFeature(&'static str),
+}
+
+#[cfg(test)]
+mod test {
+    use std::error::Error;
+
+    use super::*;
+
+    fn read_missing() -> Result<String, AocError> {
+        Ok(std::fs::read_to_string("assets/day-00-missing.input")?)
+    }
+
+    #[test]
+    fn io_error_message() {
+        let error = AocError::from(io::Error::new(io::ErrorKind::NotFound, "no such puzzle"));
+
+        assert_eq!(
+            error.to_string(),
+            "Could not access puzzle input: no such puzzle"
+        );
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn io_error_conversion() {
+        match read_missing() {
+            Err(AocError::Io(error)) => assert_eq!(error.kind(), io::ErrorKind::NotFound),
+            other => panic!("Expected an IO error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn other_error_messages() {
+        assert_eq!(
+            AocError::UnknownDay(26).to_string(),
+            "Day 26 doesn't exist, try 1 to 25"
+        );
+        assert_eq!(
+            AocError::MissingFeature("fetch").to_string(),
+            "Rebuild with the `fetch` feature to do this"
+        );
+    }
+}
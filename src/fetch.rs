@@ -0,0 +1,134 @@
+//! Talking to adventofcode.com, for everything that needs a logged in session.
+
+use crate::AocError;
+
+/// The environment variable holding the value of the `session` cookie from a
+/// logged in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Reads the session token out of `AOC_SESSION`.
+pub fn session_from_env() -> Result<String, AocError> {
+    std::env::var(SESSION_VAR)
+        .map(|session| session.trim().to_string())
+        .ok()
+        .filter(|session| !session.is_empty())
+        .ok_or_else(|| AocError::Fetch(format!("Set {} to your session cookie", SESSION_VAR)))
+}
+
+/// Where a day's input can be downloaded from.
+pub fn input_url(year: u16, day: u8) -> String {
+    format!("https://adventofcode.com/{}/day/{}/input", year, day)
+}
+
+/// Downloads a day's puzzle input. Every account gets a different input, so
+/// this needs the `session` cookie of a logged in user.
+pub fn download_input(year: u16, day: u8, session: &str) -> Result<String, AocError> {
+    ureq::get(&input_url(year, day))
+        .set("Cookie", &format!("session={}", session))
+        .call()
+        .map_err(|e| AocError::Fetch(e.to_string()))?
+        .into_string()
+        .map_err(AocError::from)
+}
+
+/// What Advent of Code made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    Incorrect,
+    /// Answers are rate limited, so this one wasn't checked.
+    TooRecent,
+    /// This part was already solved, or isn't unlocked yet.
+    AlreadyDone,
+}
+
+/// Submits `answer` for one part of a day.
+pub fn submit(
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+    session: &str,
+) -> Result<SubmitOutcome, AocError> {
+    let url = format!("https://adventofcode.com/{}/day/{}/answer", year, day);
+
+    let response = ureq::post(&url)
+        .set("Cookie", &format!("session={}", session))
+        .send_form(&[("level", &part.to_string()), ("answer", answer)])
+        .map_err(|e| AocError::Fetch(e.to_string()))?
+        .into_string()?;
+
+    parse_outcome(&response)
+}
+
+/// Reads the outcome out of the page returned after submitting an answer.
+pub fn parse_outcome(html: &str) -> Result<SubmitOutcome, AocError> {
+    const OUTCOMES: [(&str, SubmitOutcome); 4] = [
+        ("That's the right answer", SubmitOutcome::Correct),
+        ("That's not the right answer", SubmitOutcome::Incorrect),
+        ("You gave an answer too recently", SubmitOutcome::TooRecent),
+        (
+            "You don't seem to be solving the right level",
+            SubmitOutcome::AlreadyDone,
+        ),
+    ];
+
+    OUTCOMES
+        .iter()
+        .find(|(message, _)| html.contains(message))
+        .map(|(_, outcome)| *outcome)
+        .ok_or_else(|| AocError::Fetch("Didn't recognize the response to the answer".to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn input_urls() {
+        assert_eq!(
+            input_url(2020, 3),
+            "https://adventofcode.com/2020/day/3/input"
+        );
+        assert_eq!(
+            input_url(2021, 25),
+            "https://adventofcode.com/2021/day/25/input"
+        );
+    }
+
+    #[test]
+    fn correct_outcome() {
+        let html = r#"<main><article><p>That's the right answer! You are <em>one gold star</em> closer to saving your vacation.</p></article></main>"#;
+
+        assert_eq!(parse_outcome(html).unwrap(), SubmitOutcome::Correct);
+    }
+
+    #[test]
+    fn incorrect_outcome() {
+        let html = r#"<main><article><p>That's not the right answer; your answer is too low. If you're stuck, make sure you're using the full input data.</p></article></main>"#;
+
+        assert_eq!(parse_outcome(html).unwrap(), SubmitOutcome::Incorrect);
+    }
+
+    #[test]
+    fn too_recent_outcome() {
+        let html = r#"<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.</p></article></main>"#;
+
+        assert_eq!(parse_outcome(html).unwrap(), SubmitOutcome::TooRecent);
+    }
+
+    #[test]
+    fn already_done_outcome() {
+        let html = r#"<main><article><p>You don't seem to be solving the right level.  Did you already complete it? <a href="/2020/day/3">[Return to Day 3]</a></p></article></main>"#;
+
+        assert_eq!(parse_outcome(html).unwrap(), SubmitOutcome::AlreadyDone);
+    }
+
+    #[test]
+    fn unknown_outcome() {
+        assert!(matches!(
+            parse_outcome("<main>Please log in.</main>"),
+            Err(AocError::Fetch(_))
+        ));
+    }
+}
@@ -0,0 +1,460 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::iter::FromIterator;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum GridError {
+    /// A row (zero-indexed) didn't match the width of the first row.
+    #[error("Row {row} has {found} cells, but the grid is {expected} cells wide!")]
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+/// A dense, rectangular grid of cells, stored row by row.
+///
+/// The grid's origin is at the top left. Zero indexed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from lines of text, turning each character into a cell
+    /// with `to_cell`. The width is taken from the first line, and every other
+    /// line has to match it.
+    pub fn parse_with<F>(input: &str, to_cell: F) -> Result<Self, GridError>
+    where
+        F: Fn(char) -> T,
+    {
+        let mut lines = input.lines().filter(|line| !line.is_empty()).peekable();
+
+        let width = lines.peek().map_or(0, |line| line.chars().count());
+
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in lines {
+            let found = line.chars().count();
+            if found != width {
+                return Err(GridError::Ragged {
+                    row: height,
+                    expected: width,
+                    found,
+                });
+            }
+
+            cells.extend(line.chars().map(&to_cell));
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Builds a grid out of cells already laid out row by row. Panics if there
+    /// aren't exactly `width * height` of them.
+    pub fn from_cells(cells: Vec<T>, width: usize, height: usize) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "A {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Every cell, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// The cell at `(x, y)`, or `None` when that's off the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            let start = y * self.width;
+            Some(&self.cells[start..start + self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks(0) panics, but a grid without columns has no cells either
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The coordinates above, below, left, and right of `(x, y)` that are on
+    /// the grid.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &[(0, -1), (-1, 0), (1, 0), (0, 1)])
+    }
+
+    /// Like `neighbors4`, but including the diagonals too.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(
+            x,
+            y,
+            &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        )
+    }
+
+    fn neighbors(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let nx = x.checked_add_signed(*dx)?;
+            let ny = y.checked_add_signed(*dy)?;
+            self.get(nx, ny).map(|_| (nx, ny))
+        })
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    /// Keeps every character as it is.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |c| c)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    /// Indexes by `(x, y)`. Panics when out of bounds.
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is off the grid",
+            x,
+            y
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    /// Indexes by `(x, y)`. Panics when out of bounds.
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is off the grid",
+            x,
+            y
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// A coordinate on an unbounded grid, which knows every cell touching it.
+pub trait Coordinate: Copy + Eq + Hash {
+    /// Every adjacent coordinate, diagonals included, but not `self`.
+    fn neighbors(&self) -> Vec<Self>;
+}
+
+impl<const N: usize> Coordinate for [i32; N] {
+    fn neighbors(&self) -> Vec<Self> {
+        // every offset of -1, 0, or 1 along each axis, counting in base 3
+        (0..3usize.pow(N as u32))
+            .map(|mut digits| {
+                let mut neighbor = *self;
+                for axis in neighbor.iter_mut() {
+                    *axis += (digits % 3) as i32 - 1;
+                    digits /= 3;
+                }
+                neighbor
+            })
+            .filter(|neighbor| neighbor != self)
+            .collect()
+    }
+}
+
+/// An unbounded grid where only the active cells are stored, for puzzles
+/// whose patterns grow without limit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<C: Coordinate> {
+    active: HashSet<C>,
+}
+
+impl<C: Coordinate> SparseGrid<C> {
+    pub fn new() -> Self {
+        Self {
+            active: HashSet::new(),
+        }
+    }
+
+    pub fn activate(&mut self, cell: C) {
+        self.active.insert(cell);
+    }
+
+    pub fn is_active(&self, cell: &C) -> bool {
+        self.active.contains(cell)
+    }
+
+    /// How many cells are active.
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    pub fn active(&self) -> impl Iterator<Item = &C> {
+        self.active.iter()
+    }
+
+    /// Counts the active neighbors of every cell next to an active one. These
+    /// are the only cells that can possibly be active next generation.
+    pub fn neighborhood(&self) -> HashMap<C, usize> {
+        let mut counts = HashMap::new();
+        for cell in self.active.iter() {
+            for neighbor in cell.neighbors() {
+                *counts.entry(neighbor).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    /// Steps a generation. `rule` decides whether a cell is active next, from
+    /// whether it's active now and how many of its neighbors are.
+    pub fn step(&self, rule: impl Fn(bool, usize) -> bool) -> Self {
+        let mut neighborhood = self.neighborhood();
+        // cells without any active neighbors still get a say
+        for cell in self.active.iter() {
+            neighborhood.entry(*cell).or_insert(0);
+        }
+
+        neighborhood
+            .into_iter()
+            .filter(|(cell, count)| rule(self.is_active(cell), *count))
+            .map(|(cell, _)| cell)
+            .collect()
+    }
+}
+
+impl<C: Coordinate> Default for SparseGrid<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Coordinate> FromIterator<C> for SparseGrid<C> {
+    fn from_iter<I: IntoIterator<Item = C>>(cells: I) -> Self {
+        Self {
+            active: cells.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn seat_grid_parsing() {
+        let grid = Grid::parse_with("L.LL\n#LL.\nL.#L\n", |c| c).unwrap();
+
+        assert_eq!(grid.width(), 4);
+        assert_eq!(grid.height(), 3);
+
+        assert_eq!(grid[(0, 0)], 'L');
+        assert_eq!(grid[(1, 0)], '.');
+        assert_eq!(grid[(0, 1)], '#');
+        assert_eq!(grid[(3, 1)], '.');
+        assert_eq!(grid[(2, 2)], '#');
+    }
+
+    #[test]
+    fn mapped_grid_parsing() {
+        let grid = Grid::parse_with(".#\n#.", |c| c == '#').unwrap();
+
+        assert!(!grid[(0, 0)]);
+        assert!(grid[(1, 0)]);
+        assert!(grid[(0, 1)]);
+    }
+
+    #[test]
+    fn mutable_index() {
+        let mut grid = Grid::parse_with("L.\n.L", |c| c).unwrap();
+        grid[(1, 0)] = '#';
+
+        assert_eq!(grid, Grid::parse_with("L#\n.L", |c| c).unwrap());
+    }
+
+    #[test]
+    fn square_grid_from_str() -> Result<(), GridError> {
+        let grid = "abc\ndef\nghi\n".parse::<Grid<char>>()?;
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid[(2, 1)], 'f');
+        Ok(())
+    }
+
+    #[test]
+    fn ragged_grid() {
+        assert_eq!(
+            "abc\nde\nfgh".parse::<Grid<char>>(),
+            Err(GridError::Ragged {
+                row: 1,
+                expected: 3,
+                found: 2,
+            })
+        );
+        assert_eq!(
+            Grid::parse_with("ab\ncd\nefg\n", |c| c == 'a').unwrap_err(),
+            GridError::Ragged {
+                row: 2,
+                expected: 2,
+                found: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn empty_grid() -> Result<(), GridError> {
+        let grid = "".parse::<Grid<char>>()?;
+
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn checked_get() {
+        let grid = Grid::parse_with("L.\n.#", |c| c).unwrap();
+
+        assert_eq!(grid.get(0, 0), Some(&'L'));
+        assert_eq!(grid.get(1, 1), Some(&'#'));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+        assert_eq!(grid.get(usize::MAX, 0), None);
+    }
+
+    #[test]
+    fn grid_rows() {
+        let grid = Grid::from_cells(vec![1, 2, 3, 4, 5, 6], 3, 2);
+
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.cells().len(), 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_cells() {
+        Grid::from_cells(vec![1, 2, 3], 2, 2);
+    }
+
+    #[test]
+    fn corner_neighbors() {
+        let grid = Grid::parse_with("abc\ndef\nghi", |c| c).unwrap();
+
+        let top_left = grid.neighbors4(0, 0).collect::<Vec<_>>();
+        assert_eq!(top_left, vec![(1, 0), (0, 1)]);
+
+        let bottom_right = grid.neighbors8(2, 2).collect::<Vec<_>>();
+        assert_eq!(bottom_right, vec![(1, 1), (2, 1), (1, 2)]);
+
+        assert_eq!(grid.neighbors4(1, 1).count(), 4);
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+        assert_eq!(grid.neighbors8(2, 0).count(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds_index() {
+        let grid = Grid::parse_with("L.\n.L", |c| c).unwrap();
+        let _ = grid[(2, 0)];
+    }
+
+    /// Conway's Game of Life.
+    fn life(active: bool, neighbors: usize) -> bool {
+        neighbors == 3 || (active && neighbors == 2)
+    }
+
+    #[test]
+    fn coordinate_neighbors() {
+        assert_eq!([0, 0].neighbors().len(), 8);
+        assert_eq!([1, 2, 3].neighbors().len(), 26);
+        assert_eq!([0, 0, 0, 0].neighbors().len(), 80);
+        assert!(![4, 4].neighbors().contains(&[4, 4]));
+        assert!([4, 4].neighbors().contains(&[3, 5]));
+    }
+
+    #[test]
+    fn glider_steps() {
+        let glider = [[1, 0], [2, 1], [0, 2], [1, 2], [2, 2]]
+            .iter()
+            .copied()
+            .collect::<SparseGrid<[i32; 2]>>();
+
+        let mut grid = glider.clone();
+        for _ in 0..4 {
+            grid = grid.step(life);
+            assert_eq!(grid.len(), 5);
+        }
+
+        // after four generations the glider has moved one cell diagonally
+        let moved = glider
+            .active()
+            .map(|[x, y]| [x + 1, y + 1])
+            .collect::<SparseGrid<_>>();
+        assert_eq!(grid, moved);
+    }
+
+    #[test]
+    fn lonely_cells_die() {
+        let grid = [[0, 0], [5, 5]].iter().copied().collect::<SparseGrid<_>>();
+
+        assert!(grid.step(life).is_empty());
+        assert!(grid.step(|active, _| active).is_active(&[5, 5]));
+    }
+}
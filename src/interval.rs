@@ -0,0 +1,145 @@
+use std::ops::RangeInclusive;
+
+/// A set of integers stored as sorted, non-overlapping inclusive ranges.
+///
+/// Touching or overlapping ranges are merged as they're inserted, so
+/// `1..=3` and `4..=7` become a single `1..=7`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IntervalSet {
+    ranges: Vec<RangeInclusive<u64>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<u64>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let (mut start, mut end) = range.into_inner();
+
+        // every range that overlaps or touches the new one gets folded into it
+        let mut kept = Vec::with_capacity(self.ranges.len() + 1);
+        for existing in self.ranges.drain(..) {
+            if *existing.end() < start.saturating_sub(1)
+                || *existing.start() > end.saturating_add(1)
+            {
+                kept.push(existing);
+            } else {
+                start = start.min(*existing.start());
+                end = end.max(*existing.end());
+            }
+        }
+
+        let at = kept.partition_point(|existing| *existing.start() < start);
+        kept.insert(at, start..=end);
+        self.ranges = kept;
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        let at = self
+            .ranges
+            .partition_point(|existing| *existing.end() < value);
+        self.ranges
+            .get(at)
+            .is_some_and(|range| range.contains(&value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The merged ranges, in ascending order.
+    pub fn ranges(&self) -> &[RangeInclusive<u64>] {
+        &self.ranges
+    }
+}
+
+impl Extend<RangeInclusive<u64>> for IntervalSet {
+    fn extend<I: IntoIterator<Item = RangeInclusive<u64>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl std::iter::FromIterator<RangeInclusive<u64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<u64>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn merging_ranges() {
+        let set = vec![5..=7, 1..=3, 13..=40, 6..=11, 45..=50, 33..=44]
+            .into_iter()
+            .collect::<IntervalSet>();
+
+        assert_eq!(set.ranges(), &[1..=3, 5..=11, 13..=50]);
+    }
+
+    #[test]
+    fn inserting_overlapping_ranges() {
+        let mut set = IntervalSet::new();
+        set.insert(10..=20);
+        set.insert(30..=40);
+        assert_eq!(set.ranges(), &[10..=20, 30..=40]);
+
+        set.insert(15..=25);
+        assert_eq!(set.ranges(), &[10..=25, 30..=40]);
+
+        // touching counts as overlapping, and one range can swallow several
+        set.insert(26..=29);
+        assert_eq!(set.ranges(), &[10..=40]);
+        set.insert(0..=100);
+        assert_eq!(set.ranges(), &[0..=100]);
+    }
+
+    #[test]
+    fn boundary_values() {
+        let mut set = IntervalSet::new();
+        set.insert(0..=0);
+        set.insert(u64::MAX..=u64::MAX);
+        set.insert(20..=30);
+
+        assert!(set.contains(0));
+        assert!(!set.contains(1));
+        assert!(!set.contains(19));
+        assert!(set.contains(20));
+        assert!(set.contains(30));
+        assert!(!set.contains(31));
+        assert!(!set.contains(u64::MAX - 1));
+        assert!(set.contains(u64::MAX));
+    }
+
+    #[test]
+    fn contains_values() {
+        let set = vec![1..=3, 5..=7].into_iter().collect::<IntervalSet>();
+
+        assert!(!set.contains(0));
+        assert!(set.contains(1));
+        assert!(set.contains(3));
+        assert!(!set.contains(4));
+        assert!(set.contains(7));
+        assert!(!set.contains(8));
+    }
+
+    #[test]
+    fn empty_set() {
+        let mut set = IntervalSet::new();
+        #[allow(clippy::reversed_empty_ranges)]
+        set.insert(3..=1);
+
+        assert!(set.is_empty());
+        assert!(!set.contains(2));
+    }
+}
@@ -1,6 +1,55 @@
 use std::fs::File;
 use std::io::Read;
 use std::path::Path;
+use std::time::{Duration, Instant};
+
+mod answer;
+#[cfg(feature = "serde")]
+pub mod check;
+pub mod cli;
+pub mod days;
+pub mod direction;
+mod error;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+pub mod grid;
+pub mod interval;
+pub mod math;
+pub mod point;
+pub mod prelude;
+mod report;
+pub mod search;
+mod solver;
+
+/// The lexer every day's parsing is built on, so examples and downstream code
+/// don't end up on a different version than the crate.
+///
+/// ```
+/// use advent_of_rust::Logos;
+///
+/// #[derive(advent_of_rust::Logos, Debug, PartialEq)]
+/// enum Seat {
+///     #[token("L")]
+///     Empty,
+///
+///     #[token("#")]
+///     Occupied,
+///
+///     #[error]
+///     #[regex(r"[ \t\n\f]+", advent_of_rust::logos::skip)]
+///     Error,
+/// }
+///
+/// let seats = Seat::lexer("L#\nL").collect::<Vec<_>>();
+/// assert_eq!(seats, vec![Seat::Empty, Seat::Occupied, Seat::Empty]);
+/// ```
+pub use logos;
+pub use logos::{Lexer, Logos};
+
+pub use answer::Answer;
+pub use error::AocError;
+pub use report::DayResult;
+pub use solver::{input_path, parts, run, run_parts, Day, Solver};
 
 pub fn load_file<P: AsRef<Path>>(path: P) -> Result<String, std::io::Error> {
     let mut file = File::open(path)?;
@@ -10,3 +59,139 @@ pub fn load_file<P: AsRef<Path>>(path: P) -> Result<String, std::io::Error> {
 
     Ok(contents)
 }
+
+/// Like `load_file`, but the error says which file couldn't be read.
+pub fn load_input<P: AsRef<Path>>(path: P) -> Result<String, AocError> {
+    let path = path.as_ref();
+    load_file(path).map_err(|source| AocError::Input {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// The one-based line and column of `byte_offset` in `source`, for pointing
+/// at a lexer's spans. Columns count characters rather than bytes, so
+/// multibyte text doesn't push positions further right than they appear.
+///
+/// Panics if `byte_offset` isn't on a character boundary of `source`.
+pub fn line_col(source: &str, byte_offset: usize) -> (usize, usize) {
+    let before = &source[..byte_offset];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Finds the one value missing from `sorted`, which should otherwise cover
+/// every integer between its first and last values. Returns `None` when
+/// nothing is missing, when more than one value is, or when `sorted` isn't.
+pub fn missing_in_contiguous(sorted: &[u32]) -> Option<u32> {
+    let mut missing = None;
+
+    // every step has to be 1, apart from exactly one step of 2 over the gap
+    for pair in sorted.windows(2) {
+        match pair[1].checked_sub(pair[0]) {
+            Some(1) => {}
+            Some(2) if missing.is_none() => missing = Some(pair[0] + 1),
+            _ => return None,
+        }
+    }
+
+    missing
+}
+
+/// Splits puzzle input into its blank-line separated paragraphs, trimmed of
+/// surrounding whitespace.
+pub fn paragraphs(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|paragraph| !paragraph.is_empty())
+}
+
+/// Runs `f`, returning its value along with how long it took.
+pub fn timed_value<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn paragraphs_test() {
+        let input = "abc\n\na\nb\nc\n\n\n\nab\nac\n";
+
+        let found = paragraphs(input).collect::<Vec<&str>>();
+
+        assert_eq!(found, vec!["abc", "a\nb\nc", "ab\nac"]);
+    }
+
+    #[test]
+    fn load_input_names_the_file() {
+        let error = load_input("assets/day-00-missing.input").unwrap_err();
+
+        match &error {
+            AocError::Input { path, source } => {
+                assert_eq!(path, Path::new("assets/day-00-missing.input"));
+                assert_eq!(source.kind(), std::io::ErrorKind::NotFound);
+            }
+            other => panic!("Expected an input error, got {:?}", other),
+        }
+        assert!(error
+            .to_string()
+            .starts_with("Could not read assets/day-00-missing.input: "));
+    }
+
+    #[test]
+    fn missing_in_contiguous_test() {
+        assert_eq!(missing_in_contiguous(&[3, 4, 6, 7]), Some(5));
+        assert_eq!(missing_in_contiguous(&[1, 3]), Some(2));
+        assert_eq!(missing_in_contiguous(&[1, 2, 3]), None);
+        assert_eq!(missing_in_contiguous(&[1, 3, 5]), None);
+        assert_eq!(missing_in_contiguous(&[1, 4]), None);
+        assert_eq!(missing_in_contiguous(&[8]), None);
+        assert_eq!(missing_in_contiguous(&[]), None);
+        assert_eq!(missing_in_contiguous(&[5, 3]), None);
+        assert_eq!(missing_in_contiguous(&[1, 4, 2, 5]), None);
+        assert_eq!(missing_in_contiguous(&[0, 2, 1, 4]), None);
+        assert_eq!(missing_in_contiguous(&[1, 3, 3, 5]), None);
+        assert_eq!(missing_in_contiguous(&[1, 3, 5, 5]), None);
+        assert_eq!(missing_in_contiguous(&[1, 3, 3]), None);
+    }
+
+    #[test]
+    fn line_col_test() {
+        let source = "1-3 a: abcde\n2-9 é: ééé\n";
+
+        assert_eq!(line_col(source, 0), (1, 1));
+        assert_eq!(line_col(source, 4), (1, 5));
+        assert_eq!(line_col(source, 12), (1, 13));
+        assert_eq!(line_col(source, 13), (2, 1));
+        assert_eq!(line_col(source, 21), (2, 8));
+        assert_eq!(line_col(source, source.len()), (3, 1));
+        assert_eq!(line_col("abc", 3), (1, 4));
+        assert_eq!(line_col("", 0), (1, 1));
+    }
+
+    #[test]
+    fn timed_value_test() {
+        let (value, elapsed) = timed_value(|| {
+            std::thread::sleep(Duration::from_millis(5));
+            42
+        });
+
+        assert_eq!(value, 42);
+        assert!(elapsed >= Duration::from_millis(5));
+    }
+
+    #[test]
+    fn empty_paragraphs_test() {
+        assert_eq!(paragraphs("").count(), 0);
+        assert_eq!(paragraphs("\n\n\n").count(), 0);
+    }
+}
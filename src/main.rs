@@ -0,0 +1,12 @@
+use std::process;
+
+use clap::Parser;
+
+use advent_of_rust::cli::Cli;
+
+fn main() {
+    if let Err(error) = Cli::parse().execute() {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,80 @@
+//! Number theory that keeps coming up in the puzzles.
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+/// Solves the congruences `x ≡ residue (mod modulus)` for every pair in
+/// `residues_and_moduli` with the Chinese Remainder Theorem, returning the
+/// smallest non-negative `x`.
+///
+/// The congruences are folded together one at a time, so moduli that share a
+/// factor work too as long as they agree. Returns `None` when they can't all
+/// hold at once, or when a modulus isn't positive.
+///
+/// ```
+/// use advent_of_rust::math::crt;
+///
+/// assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some(23));
+/// ```
+pub fn crt(residues_and_moduli: &[(i128, i128)]) -> Option<i128> {
+    let mut solution: i128 = 0;
+    let mut period: i128 = 1;
+
+    for &(residue, modulus) in residues_and_moduli {
+        if modulus <= 0 {
+            return None;
+        }
+        let residue = residue.rem_euclid(modulus);
+
+        // solve `solution + period * k ≡ residue (mod modulus)` for `k`
+        let (g, inverse, _) = extended_gcd(period, modulus);
+        let gap = residue - solution;
+        if gap % g != 0 {
+            return None;
+        }
+        let step = modulus / g;
+        let k = ((gap / g) * inverse).rem_euclid(step);
+
+        solution += period * k;
+        period *= step;
+        solution = solution.rem_euclid(period);
+    }
+
+    Some(solution)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn extended_gcd_test() {
+        assert_eq!(extended_gcd(240, 46).0, 2);
+
+        let (g, x, y) = extended_gcd(59, 31);
+        assert_eq!(g, 1);
+        assert_eq!(59 * x + 31 * y, 1);
+    }
+
+    #[test]
+    fn crt_test() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some(23));
+        assert_eq!(crt(&[(-1, 3), (0, 4)]), Some(8));
+        assert_eq!(crt(&[(5, 8)]), Some(5));
+        assert_eq!(crt(&[]), Some(0));
+    }
+
+    #[test]
+    fn crt_shared_factors() {
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some(9));
+        assert_eq!(crt(&[(0, 2), (1, 4)]), None);
+        assert_eq!(crt(&[(1, 0)]), None);
+    }
+}
@@ -0,0 +1,105 @@
+use std::ops::{Add, Mul, Sub};
+
+/// A position or offset on an unbounded 2D plane.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The distance from the origin, moving only along the axes.
+    pub fn manhattan(&self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// Turns around the origin clockwise by `degrees`, or counter-clockwise
+    /// when it's negative, with north along positive `y` like `Direction`.
+    /// Panics unless `degrees` is a multiple of 90.
+    pub fn rotate(self, degrees: i32) -> Self {
+        assert!(
+            degrees % 90 == 0,
+            "Can only rotate by multiples of 90 degrees, not {}",
+            degrees
+        );
+
+        match (degrees / 90).rem_euclid(4) {
+            1 => Point::new(self.y, -self.x),
+            2 => Point::new(-self.x, -self.y),
+            3 => Point::new(-self.y, self.x),
+            _ => self,
+        }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, scale: i64) -> Point {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn addition() {
+        assert_eq!(Point::new(10, 4) + Point::new(-3, 7), Point::new(7, 11));
+        assert_eq!(Point::new(10, 4) - Point::new(-3, 7), Point::new(13, -3));
+        assert_eq!(Point::ORIGIN + Point::new(2, 5), Point::new(2, 5));
+    }
+
+    #[test]
+    fn scalar_multiply() {
+        assert_eq!(Point::new(10, 1) * 10, Point::new(100, 10));
+        assert_eq!(Point::new(-2, 3) * -4, Point::new(8, -12));
+    }
+
+    #[test]
+    fn rotation() {
+        let waypoint = Point::new(10, 4);
+
+        assert_eq!(waypoint.rotate(90), Point::new(4, -10));
+        assert_eq!(waypoint.rotate(-90), Point::new(-4, 10));
+        assert_eq!(waypoint.rotate(180), Point::new(-10, -4));
+        assert_eq!(waypoint.rotate(270), waypoint.rotate(-90));
+        assert_eq!(waypoint.rotate(360), waypoint);
+    }
+
+    #[test]
+    #[should_panic(expected = "multiples of 90")]
+    fn rotation_by_odd_angle() {
+        Point::new(1, 0).rotate(45);
+    }
+
+    #[test]
+    fn manhattan_distance() {
+        assert_eq!(Point::new(17, 8).manhattan(), 25);
+        assert_eq!(Point::new(17, -8).manhattan(), 25);
+        assert_eq!((Point::new(214, -72) - Point::new(4, 2)).manhattan(), 284);
+    }
+}
@@ -0,0 +1,13 @@
+//! The items most puzzles reach for, to pull in with a single
+//! `use advent_of_rust::prelude::*;`.
+
+pub use crate::direction::Direction;
+pub use crate::grid::{Grid, SparseGrid};
+pub use crate::interval::IntervalSet;
+pub use crate::math::crt;
+pub use crate::point::Point;
+pub use crate::search::{bfs, dijkstra, flood_fill};
+pub use crate::{
+    input_path, line_col, load_file, load_input, paragraphs, run, timed_value, Answer, AocError,
+    Day, Solver,
+};
@@ -0,0 +1,73 @@
+use std::convert::TryFrom;
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Answer;
+
+/// One part of a day, solved and timed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DayResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed_us: u64,
+}
+
+impl DayResult {
+    pub fn new(day: u8, part: u8, answer: Answer, elapsed: Duration) -> Self {
+        Self {
+            day,
+            part,
+            answer,
+            elapsed_us: u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX),
+        }
+    }
+
+    /// The result as a single line of JSON, for NDJSON output.
+    #[cfg(feature = "serde")]
+    pub fn to_json_line(&self) -> String {
+        serde_json::to_string(self).expect("Day results are always serializable!")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn elapsed_in_micros() {
+        let result = DayResult::new(1, 2, Answer::Int(3), Duration::from_millis(4));
+
+        assert_eq!(result.elapsed_us, 4000);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_line() {
+        let result = DayResult::new(3, 1, Answer::Int(7), Duration::from_micros(152));
+
+        let line = result.to_json_line();
+        assert!(!line.contains('\n'));
+
+        let json = serde_json::from_str::<serde_json::Value>(&line).unwrap();
+        assert_eq!(json["day"], 3);
+        assert_eq!(json["part"], 1);
+        assert_eq!(json["answer"], 7);
+        assert_eq!(json["elapsed_us"], 152);
+        assert_eq!(json.as_object().map(|keys| keys.len()), Some(4));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_line_with_string_answer() {
+        let result = DayResult::new(23, 1, Answer::from("67384529"), Duration::ZERO);
+
+        assert_eq!(
+            result.to_json_line(),
+            r#"{"day":23,"part":1,"answer":"67384529","elapsed_us":0}"#
+        );
+    }
+}
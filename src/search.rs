@@ -0,0 +1,193 @@
+//! Searches over graphs that are only known by how to get from one node to
+//! its neighbors.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Counts the fewest steps from `start` to the first node satisfying `goal`,
+/// or `None` if no such node can be reached.
+pub fn bfs<N, I>(start: N, neighbors: impl Fn(&N) -> I, goal: impl Fn(&N) -> bool) -> Option<usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::new();
+    let mut frontier = VecDeque::new();
+
+    visited.insert(start.clone());
+    frontier.push_back((start, 0));
+
+    while let Some((node, steps)) = frontier.pop_front() {
+        if goal(&node) {
+            return Some(steps);
+        }
+
+        for next in neighbors(&node) {
+            if visited.insert(next.clone()) {
+                frontier.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds the cheapest total cost from `start` to the first node satisfying
+/// `goal`, where `neighbors` gives each node's neighbors along with the cost of
+/// stepping to them. Returns `None` if no such node can be reached.
+pub fn dijkstra<N>(
+    start: N,
+    neighbors: impl Fn(&N) -> Vec<(N, u64)>,
+    goal: impl Fn(&N) -> bool,
+) -> Option<u64>
+where
+    N: Ord + Hash + Clone,
+{
+    let mut best = HashMap::new();
+    let mut frontier = BinaryHeap::new();
+
+    best.insert(start.clone(), 0);
+    frontier.push(Reverse((0, start)));
+
+    while let Some(Reverse((cost, node))) = frontier.pop() {
+        if goal(&node) {
+            return Some(cost);
+        }
+
+        // a cheaper way here was already found after this one was queued
+        if best.get(&node).is_some_and(|&known| known < cost) {
+            continue;
+        }
+
+        for (next, step) in neighbors(&node) {
+            let next_cost = cost + step;
+            if best.get(&next).is_none_or(|&known| next_cost < known) {
+                best.insert(next.clone(), next_cost);
+                frontier.push(Reverse((next_cost, next)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Every node reachable from `start`, including `start` itself.
+pub fn flood_fill<N>(start: N, neighbors: impl Fn(&N) -> Vec<N>) -> HashSet<N>
+where
+    N: Eq + Hash + Clone,
+{
+    let mut reached = HashSet::new();
+    let mut pending = vec![start.clone()];
+    reached.insert(start);
+
+    while let Some(node) = pending.pop() {
+        for next in neighbors(&node) {
+            if reached.insert(next.clone()) {
+                pending.push(next);
+            }
+        }
+    }
+
+    reached
+}
+
+#[cfg(test)]
+mod test {
+    use indoc::indoc;
+
+    use super::*;
+    use crate::grid::Grid;
+
+    const MAZE: &str = indoc! {"
+        S.#....
+        .##.##.
+        ...#..E
+        .#...#.
+    "};
+
+    fn open_neighbors(maze: &Grid<char>) -> impl Fn(&(usize, usize)) -> Vec<(usize, usize)> + '_ {
+        move |&(x, y)| {
+            maze.neighbors4(x, y)
+                .filter(|&(nx, ny)| maze[(nx, ny)] != '#')
+                .collect()
+        }
+    }
+
+    #[test]
+    fn bfs_through_a_maze() {
+        let maze = Grid::parse_with(MAZE, |c| c).unwrap();
+
+        let steps = bfs((0, 0), open_neighbors(&maze), |&(x, y)| maze[(x, y)] == 'E');
+
+        assert_eq!(steps, Some(10));
+    }
+
+    #[test]
+    fn bfs_at_the_goal() {
+        assert_eq!(bfs(5, |n| vec![n + 1], |n| *n == 5), Some(0));
+    }
+
+    #[test]
+    fn bfs_without_a_path() {
+        let maze = Grid::parse_with("S#E\n.#.\n", |c| c).unwrap();
+
+        assert_eq!(
+            bfs((0, 0), open_neighbors(&maze), |&(x, y)| maze[(x, y)] == 'E'),
+            None
+        );
+    }
+
+    fn weighted_edges(node: &char) -> Vec<(char, u64)> {
+        //   A --7-- B --1-- E
+        //   |       |       |
+        //   1       2       9
+        //   |       |       |
+        //   C --3-- D --8-- F
+        match node {
+            'A' => vec![('B', 7), ('C', 1)],
+            'B' => vec![('A', 7), ('D', 2), ('E', 1)],
+            'C' => vec![('A', 1), ('D', 3)],
+            'D' => vec![('B', 2), ('C', 3), ('F', 8)],
+            'E' => vec![('B', 1), ('F', 9)],
+            'F' => vec![('D', 8), ('E', 9)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_shortest_cost() {
+        assert_eq!(dijkstra('A', weighted_edges, |n| *n == 'E'), Some(7));
+        assert_eq!(dijkstra('A', weighted_edges, |n| *n == 'F'), Some(12));
+        assert_eq!(dijkstra('F', weighted_edges, |n| *n == 'A'), Some(12));
+        assert_eq!(dijkstra('C', weighted_edges, |n| *n == 'C'), Some(0));
+    }
+
+    #[test]
+    fn dijkstra_without_a_path() {
+        assert_eq!(dijkstra('A', weighted_edges, |n| *n == 'Z'), None);
+    }
+
+    #[test]
+    fn flood_fill_one_region() {
+        let regions = Grid::parse_with("##..\n#...\n..##\n.###\n", |c| c).unwrap();
+        let same_region = |&(x, y): &(usize, usize)| {
+            regions
+                .neighbors4(x, y)
+                .filter(|&next| regions[next] == regions[(x, y)])
+                .collect::<Vec<_>>()
+        };
+
+        let top_left = flood_fill((0, 0), same_region);
+        assert_eq!(top_left, [(0, 0), (1, 0), (0, 1)].iter().copied().collect());
+
+        let bottom_right = flood_fill((3, 3), same_region);
+        assert_eq!(bottom_right.len(), 5);
+        assert!(!bottom_right.contains(&(0, 0)));
+    }
+
+    #[test]
+    fn flood_fill_alone() {
+        assert_eq!(flood_fill(1, |_| vec![]), [1].iter().copied().collect());
+    }
+}
@@ -0,0 +1,166 @@
+use std::path::PathBuf;
+
+use crate::{load_input, Answer, AocError};
+
+/// A day's puzzle, solved from the raw text of its input.
+pub trait Solver {
+    type Output: Into<Answer>;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError>;
+
+    fn part2(input: &str) -> Result<Self::Output, AocError>;
+}
+
+/// A `Solver` for a particular day, which knows where to find its input.
+pub trait Day: Solver {
+    const DAY: u8;
+
+    fn default_input() -> Result<String, AocError> {
+        load_input(input_path(Self::DAY, "a"))
+    }
+}
+
+/// Declares a day's struct along with its `Solver` and `Day` impls, from the
+/// day number and a closure for each part. The closures can answer with
+/// anything that converts into an `Answer`.
+///
+/// ```
+/// advent_of_rust::day!(pub Lines, 6, |input: &str| Ok(input.lines().count()), |input: &str| {
+///     Ok(input.lines().rev().collect::<Vec<_>>().join(","))
+/// });
+///
+/// use advent_of_rust::{run, Answer, Day};
+///
+/// assert_eq!(Lines::DAY, 6);
+/// assert_eq!(run::<Lines>(2, "a\nb").unwrap(), Answer::from("b,a"));
+/// ```
+#[macro_export]
+macro_rules! day {
+    ($vis:vis $name:ident, $day:expr, $part1:expr, $part2:expr $(,)?) => {
+        $vis struct $name;
+
+        impl $crate::Solver for $name {
+            type Output = $crate::Answer;
+
+            fn part1(input: &str) -> Result<Self::Output, $crate::AocError> {
+                let solve = $part1;
+                solve(input).map(Into::into)
+            }
+
+            fn part2(input: &str) -> Result<Self::Output, $crate::AocError> {
+                let solve = $part2;
+                solve(input).map(Into::into)
+            }
+        }
+
+        impl $crate::Day for $name {
+            const DAY: u8 = $day;
+        }
+    };
+}
+
+/// Solves one `part` of a day.
+pub fn run<S: Solver>(part: u8, input: &str) -> Result<Answer, AocError> {
+    match part {
+        1 => S::part1(input).map(Into::into),
+        2 => S::part2(input).map(Into::into),
+        _ => Err(AocError::UnknownPart(part)),
+    }
+}
+
+/// The parts to run: just `part` if one was picked, otherwise both.
+pub fn parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+/// Solves the given `part` of a day, or both parts when it's `None`. Each
+/// answer is labelled with the part it belongs to.
+pub fn run_parts<S: Solver>(part: Option<u8>, input: &str) -> Result<Vec<(u8, Answer)>, AocError> {
+    parts(part)
+        .into_iter()
+        .map(|part| run::<S>(part, input).map(|answer| (part, answer)))
+        .collect()
+}
+
+/// Where a day's input lives, like `assets/day-03-a.input`.
+pub fn input_path(day: u8, suffix: &str) -> PathBuf {
+    PathBuf::from(format!("assets/day-{:02}-{}.input", day, suffix))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Echo;
+
+    impl Solver for Echo {
+        type Output = String;
+
+        fn part1(input: &str) -> Result<Self::Output, AocError> {
+            Ok(input.to_uppercase())
+        }
+
+        fn part2(input: &str) -> Result<Self::Output, AocError> {
+            Ok(input.chars().rev().collect())
+        }
+    }
+
+    #[test]
+    fn run_each_part() {
+        assert_eq!(run::<Echo>(1, "abc").unwrap(), Answer::from("ABC"));
+        assert_eq!(run::<Echo>(2, "abc").unwrap(), Answer::from("cba"));
+    }
+
+    #[test]
+    fn run_unknown_part() {
+        match run::<Echo>(3, "abc") {
+            Err(AocError::UnknownPart(3)) => {}
+            other => panic!("Expected an unknown part, got {:?}", other),
+        }
+    }
+
+    crate::day!(
+        Sums,
+        12,
+        |input: &str| input
+            .split(',')
+            .map(|n| n.parse::<i64>().map_err(|e| AocError::Parse(e.to_string())))
+            .sum::<Result<i64, _>>(),
+        |input: &str| Ok(input.replace(',', "+")),
+    );
+
+    #[test]
+    fn macro_declared_day() {
+        assert_eq!(Sums::DAY, 12);
+        assert_eq!(run::<Sums>(1, "1,2,3").unwrap(), Answer::Int(6));
+        assert_eq!(run::<Sums>(2, "1,2,3").unwrap(), Answer::from("1+2+3"));
+        assert!(matches!(run::<Sums>(1, "1,x"), Err(AocError::Parse(_))));
+        assert_eq!(run_parts::<Sums>(None, "4").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn run_both_parts_by_default() {
+        assert_eq!(
+            run_parts::<Echo>(None, "abc").unwrap(),
+            vec![(1, Answer::from("ABC")), (2, Answer::from("cba"))]
+        );
+    }
+
+    #[test]
+    fn run_single_part() {
+        assert_eq!(
+            run_parts::<Echo>(Some(2), "abc").unwrap(),
+            vec![(2, Answer::from("cba"))]
+        );
+        assert!(run_parts::<Echo>(Some(3), "abc").is_err());
+    }
+
+    #[test]
+    fn default_input_path() {
+        assert_eq!(input_path(3, "a"), PathBuf::from("assets/day-03-a.input"));
+        assert_eq!(input_path(12, "b"), PathBuf::from("assets/day-12-b.input"));
+    }
+}
@@ -0,0 +1,159 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use advent_of_rust::cli::{Cli, Command, Format};
+
+#[test]
+fn parse_run() {
+    let cli = Cli::try_parse_from(["advent-of-rust", "run", "--day", "3", "--part", "2"]).unwrap();
+
+    assert_eq!(
+        cli.command,
+        Command::Run {
+            day: 3,
+            part: Some(2),
+            input: None,
+            format: Format::Text,
+            check: None
+        }
+    );
+}
+
+#[test]
+fn parse_run_with_input() {
+    let cli = Cli::try_parse_from([
+        "advent-of-rust",
+        "run",
+        "-d",
+        "1",
+        "-p",
+        "1",
+        "-i",
+        "sample.txt",
+    ])
+    .unwrap();
+
+    assert_eq!(
+        cli.command,
+        Command::Run {
+            day: 1,
+            part: Some(1),
+            input: Some(PathBuf::from("sample.txt")),
+            format: Format::Text,
+            check: None
+        }
+    );
+}
+
+#[test]
+fn parse_run_both_parts() {
+    let cli = Cli::try_parse_from(["advent-of-rust", "run", "--day", "4"]).unwrap();
+
+    assert_eq!(
+        cli.command,
+        Command::Run {
+            day: 4,
+            part: None,
+            input: None,
+            format: Format::Text,
+            check: None
+        }
+    );
+}
+
+#[test]
+fn parse_run_as_ndjson() {
+    let cli =
+        Cli::try_parse_from(["advent-of-rust", "run", "-d", "2", "--format", "ndjson"]).unwrap();
+
+    assert_eq!(
+        cli.command,
+        Command::Run {
+            day: 2,
+            part: None,
+            input: None,
+            format: Format::Ndjson,
+            check: None
+        }
+    );
+    assert!(Cli::try_parse_from(["advent-of-rust", "run", "-d", "2", "-f", "xml"]).is_err());
+}
+
+#[test]
+fn parse_run_with_check() {
+    let cli = Cli::try_parse_from([
+        "advent-of-rust",
+        "run",
+        "-d",
+        "1",
+        "--check",
+        "answers.toml",
+    ])
+    .unwrap();
+
+    assert_eq!(
+        cli.command,
+        Command::Run {
+            day: 1,
+            part: None,
+            input: None,
+            format: Format::Text,
+            check: Some(PathBuf::from("answers.toml"))
+        }
+    );
+}
+
+#[test]
+fn run_requires_a_day() {
+    assert!(Cli::try_parse_from(["advent-of-rust", "run", "--part", "1"]).is_err());
+}
+
+#[test]
+fn parse_download() {
+    let cli = Cli::try_parse_from(["advent-of-rust", "download", "--day", "5"]).unwrap();
+
+    assert_eq!(
+        cli.command,
+        Command::Download {
+            day: 5,
+            year: 2020,
+            force: false
+        }
+    );
+}
+
+#[test]
+fn parse_download_with_force() {
+    let cli = Cli::try_parse_from([
+        "advent-of-rust",
+        "download",
+        "--day",
+        "7",
+        "--year",
+        "2021",
+        "--force",
+    ])
+    .unwrap();
+
+    assert_eq!(
+        cli.command,
+        Command::Download {
+            day: 7,
+            year: 2021,
+            force: true
+        }
+    );
+}
+
+#[test]
+fn download_requires_a_day() {
+    assert!(Cli::try_parse_from(["advent-of-rust", "download", "--force"]).is_err());
+}
+
+#[test]
+fn parse_bench() {
+    let cli = Cli::try_parse_from(["advent-of-rust", "bench"]).unwrap();
+
+    assert_eq!(cli.command, Command::Bench);
+}
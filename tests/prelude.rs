@@ -0,0 +1,38 @@
+use advent_of_rust::prelude::*;
+
+struct Steps;
+
+impl Solver for Steps {
+    type Output = i64;
+
+    fn part1(input: &str) -> Result<Self::Output, AocError> {
+        let position = input
+            .chars()
+            .filter_map(|c| match c {
+                'N' => Some(Direction::North),
+                'E' => Some(Direction::East),
+                'S' => Some(Direction::South),
+                'W' => Some(Direction::West),
+                _ => None,
+            })
+            .fold(Point::ORIGIN, |position, heading| {
+                position + heading.delta()
+            });
+
+        Ok(position.manhattan())
+    }
+
+    fn part2(input: &str) -> Result<Self::Output, AocError> {
+        let grid = input
+            .parse::<Grid<char>>()
+            .map_err(|e| AocError::Parse(e.to_string()))?;
+        Ok((grid.width() * grid.height()) as i64)
+    }
+}
+
+#[test]
+fn prelude_items() -> Result<(), AocError> {
+    assert_eq!(run::<Steps>(1, "NNEESW")?, Answer::Int(2));
+    assert_eq!(run::<Steps>(2, "NNE\nESW\n")?, Answer::Int(6));
+    Ok(())
+}